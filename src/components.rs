@@ -1,11 +1,14 @@
-use std::time::Duration;
+use std::{marker::PhantomData, time::Duration};
 
+use crate::core::{save::write_save, time::DeltaTime};
 use assert_assets_path::assert_assets_path;
 use bevy::{audio::Volume, prelude::*};
+use derive_new::new;
 use seldom_pixel::{
     asset::{PxAsset, PxAssets},
     filter::{PxFilter, PxFilterData},
 };
+use serde::{Deserialize, Serialize};
 
 #[derive(Component)]
 pub enum AudioSystemType {
@@ -18,7 +21,9 @@ pub struct AudioSystemBundle {
     pub system_type: AudioSystemType,
 }
 
-#[derive(Clone, Component, Copy, Default, Debug, Reflect, PartialEq, Eq, Hash)]
+#[derive(
+    Clone, Component, Copy, Default, Debug, Deserialize, Reflect, PartialEq, Eq, Hash, Serialize,
+)]
 pub enum GBColor {
     #[default]
     Black,
@@ -64,6 +69,61 @@ pub struct DespawnMark;
 #[derive(Component)]
 pub struct Music;
 
+/**
+ * Current volume of an audio entity, as a fraction of its VolumeSettings channel
+ */
+#[derive(Clone, Component, Copy, Debug)]
+pub struct VolumeLevel(pub f32);
+
+impl Default for VolumeLevel {
+    fn default() -> Self {
+        Self(1.)
+    }
+}
+
+/**
+ * Moves the VolumeLevel of an audio entity over time, following the clock T
+ */
+#[derive(new, Clone, Component, Debug)]
+pub struct VolumeFade<T: DeltaTime> {
+    pub from: f32,
+    pub to: f32,
+    pub duration: Duration,
+    #[new(default)]
+    pub elapsed: Duration,
+    #[new(default)]
+    pub despawn_on_finish: bool,
+    #[new(default)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: DeltaTime> VolumeFade<T> {
+    pub fn fade_in(duration: Duration) -> Self {
+        Self::new(0., 1., duration)
+    }
+
+    pub fn fade_out(from: f32, duration: Duration) -> Self {
+        Self::new(from, 0., duration).with_despawn_on_finish()
+    }
+
+    pub fn with_despawn_on_finish(mut self) -> Self {
+        self.despawn_on_finish = true;
+        self
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    pub fn level(&self) -> f32 {
+        if self.duration.is_zero() {
+            return self.to;
+        }
+        let t = (self.elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.);
+        self.from + (self.to - self.from) * t
+    }
+}
+
 #[derive(Component)]
 pub struct DelayedDespawnOnPxAnimationFinished(pub Duration);
 
@@ -79,14 +139,47 @@ pub struct DespawnAfterDelay {
     pub duration: Duration,
 }
 
+pub const VOLUME_SETTINGS_FILE: &str = "volume.ron";
+
 // TODO could probably split into different resources
-#[derive(Resource, Clone, Copy, Debug)]
+#[derive(Resource, Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(from = "VolumeSettingsSave", into = "VolumeSettingsSave")]
 pub struct VolumeSettings {
     pub master: Volume,
     pub music: Volume,
     pub sfx: Volume,
 }
 
+/**
+ * Volume doesn't serialize on its own, so the save holds the plain levels
+ */
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+struct VolumeSettingsSave {
+    master: f32,
+    music: f32,
+    sfx: f32,
+}
+
+impl From<VolumeSettingsSave> for VolumeSettings {
+    fn from(save: VolumeSettingsSave) -> Self {
+        Self {
+            master: Volume::new(save.master),
+            music: Volume::new(save.music),
+            sfx: Volume::new(save.sfx),
+        }
+    }
+}
+
+impl From<VolumeSettings> for VolumeSettingsSave {
+    fn from(settings: VolumeSettings) -> Self {
+        Self {
+            master: settings.master.get(),
+            music: settings.music.get(),
+            sfx: settings.sfx.get(),
+        }
+    }
+}
+
 impl Default for VolumeSettings {
     fn default() -> Self {
         Self {
@@ -96,3 +189,24 @@ impl Default for VolumeSettings {
         }
     }
 }
+
+/**
+ * Channels are set in steps, from silent up to VOLUME_STEPS
+ */
+pub const VOLUME_STEP: f32 = 0.01;
+pub const VOLUME_STEPS: u8 = 10;
+
+impl VolumeSettings {
+    pub fn level(volume: Volume) -> u8 {
+        (volume.get() / VOLUME_STEP).round() as u8
+    }
+
+    pub fn step(volume: Volume, steps: i8) -> Volume {
+        let level = (Self::level(volume) as i8 + steps).clamp(0, VOLUME_STEPS as i8);
+        Volume::new(level as f32 * VOLUME_STEP)
+    }
+
+    pub fn save(&self) {
+        write_save(VOLUME_SETTINGS_FILE, self);
+    }
+}
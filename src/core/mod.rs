@@ -1,3 +1,4 @@
 pub mod collisions;
 pub mod event;
+pub mod save;
 pub mod time;
@@ -0,0 +1,34 @@
+use serde::{de::DeserializeOwned, Serialize};
+
+pub const SAVE_DIRECTORY: &str = "saves";
+
+/**
+ * TODO wasm builds have no filesystem, persist these into local storage instead
+ */
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_save<T: DeserializeOwned>(file_name: &str) -> Option<T> {
+    let path = std::path::Path::new(SAVE_DIRECTORY).join(file_name);
+    let content = std::fs::read_to_string(path).ok()?;
+    ron::de::from_str(&content).ok()
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn load_save<T: DeserializeOwned>(_file_name: &str) -> Option<T> {
+    None
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn write_save<T: Serialize>(file_name: &str, value: &T) {
+    let result = std::fs::create_dir_all(SAVE_DIRECTORY).and_then(|_| {
+        let content = ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+        std::fs::write(std::path::Path::new(SAVE_DIRECTORY).join(file_name), content)
+    });
+
+    if let Err(e) = result {
+        bevy::log::error!("Could not write save {}: {}", file_name, e);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn write_save<T: Serialize>(_file_name: &str, _value: &T) {}
@@ -21,12 +21,9 @@ pub struct TimeMultiplier<T: Ticker> {
     pub value: f32,
 }
 
-#[cfg(not(debug_assertions))]
-pub fn tick_time<T: Ticker + Resource>(mut time: ResMut<T>, app_time: Res<Time>) {
-    time.tick(app_time.delta());
-}
-#[cfg(debug_assertions)]
-
+/**
+ * Scaled by the TimeMultiplier of the clock when there's one
+ */
 pub fn tick_time<T: Ticker + Resource>(
     mut time: ResMut<T>,
     app_time: Res<Time>,
@@ -0,0 +1,45 @@
+use crate::globals::{FONT_SIZE, SCREEN_RESOLUTION};
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct Credits;
+
+#[derive(Component)]
+pub struct CreditsEntity;
+
+/**
+ * Part of the scrolling roll, the offset counts down from its top
+ */
+#[derive(Clone, Component, Debug)]
+pub struct CreditsLine {
+    pub offset: f32,
+    pub height: u32,
+}
+
+impl CreditsLine {
+    /**
+     * The top of the roll starts at the bottom edge of the screen
+     */
+    pub fn top(&self, scrolled: f32) -> f32 {
+        scrolled - self.offset
+    }
+
+    pub fn rect(&self, scrolled: f32) -> IRect {
+        let top = self.top(scrolled) as i32;
+        IRect::new(
+            CREDITS_MARGIN,
+            top - self.height as i32,
+            SCREEN_RESOLUTION.x as i32 - CREDITS_MARGIN,
+            top,
+        )
+    }
+}
+
+pub const CREDITS_LINE_HEIGHT: u32 = FONT_SIZE + 2;
+pub const CREDITS_SECTION_GAP: u32 = 16;
+pub const CREDITS_MARGIN: i32 = 4;
+
+/**
+ * CreditsTime multiplier while fast-forward is held
+ */
+pub const CREDITS_FAST_FORWARD_MULTIPLIER: f32 = 4.;
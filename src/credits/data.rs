@@ -0,0 +1,92 @@
+use bevy::prelude::*;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/**
+ * Pixels scrolled per second
+ */
+pub const CREDITS_SCROLL_SPEED: f32 = 15.;
+
+fn default_scroll_speed() -> f32 {
+    CREDITS_SCROLL_SPEED
+}
+
+/**
+ * The height reserves room in the roll, as the sprite may not be loaded when it is laid out
+ */
+#[derive(new, Clone, Debug, Deserialize, Reflect, Serialize)]
+pub struct CreditsImage {
+    pub path: String,
+    pub height: u32,
+}
+
+#[derive(new, Clone, Debug, Default, Deserialize, Reflect, Serialize)]
+pub struct CreditsSection {
+    #[new(default)]
+    #[serde(default)]
+    pub title: Option<String>,
+    #[new(default)]
+    #[serde(default)]
+    pub names: Vec<String>,
+    /**
+     * Drawn below the names
+     */
+    #[new(default)]
+    #[serde(default)]
+    pub image: Option<CreditsImage>,
+}
+
+impl CreditsSection {
+    pub fn with_title(mut self, title: String) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    pub fn add_name(mut self, name: String) -> Self {
+        self.names.push(name);
+        self
+    }
+
+    pub fn with_image(mut self, image: CreditsImage) -> Self {
+        self.image = Some(image);
+        self
+    }
+}
+
+#[derive(new, Asset, Clone, Debug, Deserialize, Reflect, Resource, Serialize)]
+pub struct CreditsData {
+    #[new(default)]
+    #[serde(default)]
+    pub background_path: Option<String>,
+    #[new(default)]
+    #[serde(default)]
+    pub music_path: Option<String>,
+    #[new(value = "CREDITS_SCROLL_SPEED")]
+    #[serde(default = "default_scroll_speed")]
+    pub speed: f32,
+    #[new(default)]
+    #[serde(default)]
+    pub sections: Vec<CreditsSection>,
+}
+
+impl CreditsData {
+    pub fn with_background(mut self, path: String) -> Self {
+        self.background_path = Some(path);
+        self
+    }
+
+    pub fn with_music(mut self, path: String) -> Self {
+        self.music_path = Some(path);
+        self
+    }
+
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    pub fn add_section(mut self, section: CreditsSection) -> Self {
+        self.sections.push(section);
+        self
+    }
+}
@@ -0,0 +1,14 @@
+use super::data::CreditsData;
+use bevy::prelude::*;
+use std::sync::Arc;
+
+#[derive(Event)]
+pub struct CreditsStartupTrigger {
+    pub data: Arc<CreditsData>,
+}
+
+/**
+ * Sent once the roll has scrolled off the top of the screen
+ */
+#[derive(Clone, Event)]
+pub struct CreditsShutdownTrigger;
@@ -0,0 +1,19 @@
+use crate::input::{GBInput, GBInputBindings};
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use leafwing_input_manager::action_state::ActionState;
+use leafwing_input_manager::Actionlike;
+
+#[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
+pub enum CreditsInput {
+    FastForward,
+}
+
+pub fn init_input(mut commands: Commands, bindings: Res<GBInputBindings>) {
+    let ms: Vec<(CreditsInput, GBInput)> = vec![
+        (CreditsInput::FastForward, GBInput::A),
+        (CreditsInput::FastForward, GBInput::B),
+    ];
+    commands.insert_resource(ActionState::<CreditsInput>::default());
+    commands.insert_resource(bindings.make_input_map(ms));
+}
@@ -0,0 +1,67 @@
+pub mod components;
+pub mod data;
+pub mod events;
+pub mod input;
+pub mod resources;
+mod systems;
+
+use self::{
+    data::CreditsData,
+    events::{CreditsShutdownTrigger, CreditsStartupTrigger},
+    input::{init_input, CreditsInput},
+    resources::{CreditsProgress, CreditsTime},
+    systems::{
+        scroll::{check_credits_finished, update_credits_fast_forward, update_credits_scroll},
+        setup::{on_credits_shutdown, on_credits_startup},
+    },
+};
+use crate::{
+    core::{
+        event::on_trigger_write_event,
+        time::{tick_time, TimeMultiplier},
+    },
+    input::GBInputBindings,
+};
+use bevy::prelude::*;
+use bevy_common_assets::ron::RonAssetPlugin;
+use leafwing_input_manager::plugin::InputManagerPlugin;
+
+pub struct CreditsPlugin;
+
+impl Plugin for CreditsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(RonAssetPlugin::<CreditsData>::new(&["credits.ron"]))
+            .add_plugins(InputManagerPlugin::<CreditsInput>::default())
+            .init_state::<CreditsPluginUpdateState>()
+            .init_resource::<CreditsTime>()
+            .insert_resource(TimeMultiplier::<CreditsTime>::new(1.))
+            .add_event::<CreditsStartupTrigger>()
+            .observe(on_credits_startup)
+            .add_event::<CreditsShutdownTrigger>()
+            .observe(on_credits_shutdown)
+            .observe(on_trigger_write_event::<CreditsShutdownTrigger>)
+            .add_systems(
+                PreUpdate,
+                init_input.run_if(resource_changed::<GBInputBindings>),
+            )
+            .add_systems(
+                Update,
+                (
+                    update_credits_fast_forward,
+                    tick_time::<CreditsTime>,
+                    update_credits_scroll,
+                    check_credits_finished,
+                )
+                    .chain()
+                    .run_if(resource_exists::<CreditsProgress>)
+                    .run_if(in_state(CreditsPluginUpdateState::Active)),
+            );
+    }
+}
+
+#[derive(States, Debug, Clone, Eq, PartialEq, Hash, Default)]
+pub enum CreditsPluginUpdateState {
+    #[default]
+    Inactive,
+    Active,
+}
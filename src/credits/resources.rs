@@ -0,0 +1,37 @@
+use crate::core::time::*;
+use bevy::prelude::*;
+use std::time::Duration;
+
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct CreditsTime {
+    pub delta: Duration,
+    pub elapsed: Duration,
+}
+
+impl DeltaTime for CreditsTime {
+    fn delta(&self) -> Duration {
+        self.delta
+    }
+}
+
+impl ElapsedTime for CreditsTime {
+    fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+impl Ticker for CreditsTime {
+    fn tick(&mut self, delta: Duration) {
+        self.delta = delta;
+        self.elapsed += delta;
+    }
+}
+
+#[derive(Resource, Default, Clone, Copy, Debug)]
+pub struct CreditsProgress {
+    pub scrolled: f32,
+    /**
+     * Height of the whole roll
+     */
+    pub length: f32,
+}
@@ -0,0 +1,2 @@
+pub mod scroll;
+pub mod setup;
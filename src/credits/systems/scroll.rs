@@ -0,0 +1,54 @@
+use crate::{
+    core::time::{DeltaTime, TimeMultiplier},
+    credits::{
+        components::{CreditsLine, CREDITS_FAST_FORWARD_MULTIPLIER},
+        data::CreditsData,
+        events::CreditsShutdownTrigger,
+        input::CreditsInput,
+        resources::{CreditsProgress, CreditsTime},
+    },
+    globals::SCREEN_RESOLUTION,
+};
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::ActionState;
+use seldom_pixel::prelude::{PxRect, PxSubPosition};
+
+pub fn update_credits_scroll(
+    mut progress: ResMut<CreditsProgress>,
+    mut text_query: Query<(&CreditsLine, &mut PxRect)>,
+    mut sprite_query: Query<(&CreditsLine, &mut PxSubPosition)>,
+    data: Res<CreditsData>,
+    time: Res<CreditsTime>,
+) {
+    progress.scrolled += data.speed * time.delta().as_secs_f32();
+
+    for (line, mut rect) in text_query.iter_mut() {
+        *rect = line.rect(progress.scrolled).into();
+    }
+    for (line, mut position) in sprite_query.iter_mut() {
+        position.0.y = line.top(progress.scrolled);
+    }
+}
+
+/**
+ * Done once the bottom of the roll has left the top of the screen
+ */
+pub fn check_credits_finished(mut commands: Commands, progress: Res<CreditsProgress>) {
+    if progress.scrolled >= progress.length + SCREEN_RESOLUTION.y as f32 {
+        commands.trigger(CreditsShutdownTrigger);
+    }
+}
+
+pub fn update_credits_fast_forward(
+    input: Res<ActionState<CreditsInput>>,
+    mut time_multiplier: ResMut<TimeMultiplier<CreditsTime>>,
+) {
+    let value = if input.pressed(&CreditsInput::FastForward) {
+        CREDITS_FAST_FORWARD_MULTIPLIER
+    } else {
+        1.
+    };
+    if time_multiplier.value != value {
+        time_multiplier.value = value;
+    }
+}
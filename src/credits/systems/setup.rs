@@ -0,0 +1,175 @@
+use crate::{
+    components::VolumeSettings,
+    core::time::TimeMultiplier,
+    credits::{
+        components::{
+            Credits, CreditsEntity, CreditsLine, CREDITS_LINE_HEIGHT, CREDITS_SECTION_GAP,
+        },
+        data::CreditsData,
+        events::{CreditsShutdownTrigger, CreditsStartupTrigger},
+        resources::{CreditsProgress, CreditsTime},
+        CreditsPluginUpdateState,
+    },
+    cutscene::data::CutsceneLayer,
+    debug::plugin::{debug_print_shutdown, debug_print_startup},
+    globals::{mark_for_despawn_by_query, SCREEN_RESOLUTION, TYPEFACE_CHARACTERS, TYPEFACE_PATH},
+    layer::Layer,
+    systems::spawn::make_music_bundle,
+};
+use bevy::{audio::PlaybackMode, prelude::*};
+use seldom_pixel::{
+    prelude::{PxAnchor, PxAssets, PxCanvas, PxSubPosition, PxTextBundle, PxTypeface},
+    sprite::{PxSprite, PxSpriteBundle},
+};
+
+const DEBUG_MODULE: &str = "Credits";
+
+fn spawn_credits_text(
+    commands: &mut Commands,
+    typeface: &Handle<PxTypeface>,
+    text: &str,
+    offset: f32,
+) {
+    let line = CreditsLine {
+        offset,
+        height: CREDITS_LINE_HEIGHT,
+    };
+    commands.spawn((
+        Name::new("CreditsText"),
+        CreditsEntity,
+        PxTextBundle::<Layer> {
+            alignment: PxAnchor::Center,
+            canvas: PxCanvas::Camera,
+            layer: Layer::CutsceneLayer(CutsceneLayer::Text),
+            rect: line.rect(0.).into(),
+            text: text.into(),
+            typeface: typeface.clone(),
+            ..default()
+        },
+        line,
+    ));
+}
+
+/**
+ * Lays every section out below the screen, returning the height of the whole roll
+ */
+fn spawn_credits_roll(
+    commands: &mut Commands,
+    assets_sprite: &mut PxAssets<PxSprite>,
+    typefaces: &mut PxAssets<PxTypeface>,
+    data: &CreditsData,
+) -> f32 {
+    let typeface = typefaces.load(TYPEFACE_PATH, TYPEFACE_CHARACTERS, [(' ', 4)]);
+    let mut offset = 0.;
+
+    for section in data.sections.iter() {
+        if let Some(title) = &section.title {
+            spawn_credits_text(commands, &typeface, title, offset);
+            offset += (CREDITS_LINE_HEIGHT * 2) as f32;
+        }
+
+        for name in section.names.iter() {
+            spawn_credits_text(commands, &typeface, name, offset);
+            offset += CREDITS_LINE_HEIGHT as f32;
+        }
+
+        if let Some(image) = &section.image {
+            let line = CreditsLine {
+                offset: offset + CREDITS_LINE_HEIGHT as f32,
+                height: image.height,
+            };
+            commands.spawn((
+                Name::new("CreditsImage"),
+                CreditsEntity,
+                PxSpriteBundle::<Layer> {
+                    sprite: assets_sprite.load(image.path.clone()),
+                    anchor: PxAnchor::TopCenter,
+                    canvas: PxCanvas::Camera,
+                    layer: Layer::CutsceneLayer(CutsceneLayer::Middle(0)),
+                    ..default()
+                },
+                PxSubPosition(Vec2::new(SCREEN_RESOLUTION.x as f32 / 2., line.top(0.))),
+                line,
+            ));
+            offset += (image.height + CREDITS_LINE_HEIGHT) as f32;
+        }
+
+        offset += CREDITS_SECTION_GAP as f32;
+    }
+
+    offset
+}
+
+pub fn on_credits_startup(
+    trigger: Trigger<CreditsStartupTrigger>,
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<CreditsPluginUpdateState>>,
+    mut time_multiplier: ResMut<TimeMultiplier<CreditsTime>>,
+    mut assets_sprite: PxAssets<PxSprite>,
+    mut typefaces: PxAssets<PxTypeface>,
+    asset_server: Res<AssetServer>,
+    volume_settings: Res<VolumeSettings>,
+) {
+    #[cfg(debug_assertions)]
+    debug_print_startup(DEBUG_MODULE);
+
+    time_multiplier.value = 1.;
+
+    let data = trigger.event().data.as_ref();
+    next_state.set(CreditsPluginUpdateState::Active);
+
+    commands.spawn((Credits, Name::new("Credits")));
+
+    if let Some(background_path) = &data.background_path {
+        commands.spawn((
+            Name::new("CreditsBackground"),
+            CreditsEntity,
+            PxSpriteBundle::<Layer> {
+                sprite: assets_sprite.load(background_path.clone()),
+                anchor: PxAnchor::BottomLeft,
+                canvas: PxCanvas::Camera,
+                layer: Layer::CutsceneLayer(CutsceneLayer::Background(0)),
+                ..default()
+            },
+            PxSubPosition(Vec2::ZERO),
+        ));
+    }
+
+    if let Some(music_path) = &data.music_path {
+        commands.spawn((
+            Name::new("CreditsMusic"),
+            CreditsEntity,
+            make_music_bundle(
+                &asset_server,
+                &volume_settings,
+                music_path.clone(),
+                PlaybackMode::Loop,
+            ),
+        ));
+    }
+
+    let length = spawn_credits_roll(&mut commands, &mut assets_sprite, &mut typefaces, data);
+
+    commands.insert_resource::<CreditsData>(data.clone());
+    commands.insert_resource(CreditsProgress {
+        scrolled: 0.,
+        length,
+    });
+}
+
+pub fn on_credits_shutdown(
+    _trigger: Trigger<CreditsShutdownTrigger>,
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<CreditsPluginUpdateState>>,
+    credits_query: Query<Entity, With<Credits>>,
+    credits_entity_query: Query<Entity, With<CreditsEntity>>,
+) {
+    #[cfg(debug_assertions)]
+    debug_print_shutdown(DEBUG_MODULE);
+
+    next_state.set(CreditsPluginUpdateState::Inactive);
+    commands.remove_resource::<CreditsProgress>();
+
+    mark_for_despawn_by_query(&mut commands, &credits_entity_query);
+    mark_for_despawn_by_query(&mut commands, &credits_query);
+}
@@ -1,4 +1,8 @@
+use super::data::{CutsceneDialogue, CutsceneTween};
+use crate::core::time::DeltaTime;
 use bevy::prelude::*;
+use derive_new::new;
+use std::{marker::PhantomData, time::Duration};
 
 #[derive(Component)]
 pub struct CutsceneEntity;
@@ -8,3 +12,130 @@ pub struct Cinematic;
 
 #[derive(Component)]
 pub struct CutsceneGraphic;
+
+/**
+ * Everything drawn for the dialogue textbox
+ */
+#[derive(Component)]
+pub struct CutsceneTextbox;
+
+#[derive(Component)]
+pub struct CutsceneDialogueText;
+
+#[derive(Component)]
+pub struct CutsceneChoiceOption(pub usize);
+
+#[derive(Clone, Component, Debug)]
+pub struct CutsceneChoiceProgress {
+    pub options: Vec<String>,
+    pub selected: usize,
+}
+
+#[derive(Component)]
+pub struct CutsceneTypingSfx;
+
+/**
+ * Blinks while an act waits on A
+ */
+#[derive(Component)]
+pub struct CutsceneAwaitIndicator;
+
+pub const AWAIT_INDICATOR_BLINK_SECS: f32 = 0.4;
+
+/**
+ * Shown on the first Start press, a second one within the window skips the cutscene
+ */
+#[derive(Component)]
+pub struct CutsceneSkipPrompt {
+    pub shown: Duration,
+}
+
+pub const SKIP_CONFIRM_SECS: f32 = 2.;
+
+/**
+ * CutsceneTime multiplier while fast-forward is held
+ */
+pub const FAST_FORWARD_MULTIPLIER: f32 = 4.;
+
+/**
+ * Characters revealed per second
+ */
+pub const DIALOGUE_TYPING_SPEED: f32 = 30.;
+
+#[derive(Clone, Component, Debug)]
+pub struct CutsceneDialogueProgress {
+    pub dialogue: CutsceneDialogue,
+    pub line_index: usize,
+    pub line_started: Duration,
+    /**
+     * Set when the rest of the line is revealed at once
+     */
+    pub line_completed: bool,
+}
+
+impl CutsceneDialogueProgress {
+    pub fn new(dialogue: CutsceneDialogue, started: Duration) -> Self {
+        Self {
+            dialogue,
+            line_index: 0,
+            line_started: started,
+            line_completed: false,
+        }
+    }
+
+    pub fn current_line(&self) -> Option<&String> {
+        self.dialogue.lines.get(self.line_index)
+    }
+
+    pub fn next_line(&mut self, started: Duration) -> Option<&String> {
+        self.line_index += 1;
+        self.line_started = started;
+        self.line_completed = false;
+        self.current_line()
+    }
+
+    pub fn revealed_count(&self, elapsed: Duration) -> usize {
+        let total = self.current_line().map_or(0, |line| line.chars().count());
+        if self.line_completed {
+            return total;
+        }
+        let typed = (elapsed.saturating_sub(self.line_started).as_secs_f32()
+            * DIALOGUE_TYPING_SPEED) as usize;
+        typed.min(total)
+    }
+
+    pub fn is_line_revealed(&self, elapsed: Duration) -> bool {
+        self.current_line().map_or(true, |line| {
+            self.revealed_count(elapsed) >= line.chars().count()
+        })
+    }
+}
+
+/**
+ * Plays the non-positional tweens of a cutscene sprite, following the clock T
+ */
+#[derive(new, Clone, Component, Debug)]
+pub struct SpriteTweens<T: DeltaTime> {
+    pub tweens: Vec<CutsceneTween>,
+    #[new(default)]
+    pub elapsed: Duration,
+    #[new(default)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: DeltaTime> SpriteTweens<T> {
+    /**
+     * Done once every keyframe has passed and no blink is left running
+     */
+    pub fn is_finished(&self) -> bool {
+        self.tweens.iter().all(|tween| match tween {
+            CutsceneTween::Blink {
+                at,
+                duration: Some(duration),
+                ..
+            } => *at + *duration < self.elapsed,
+            CutsceneTween::Blink { duration: None, .. } => false,
+            tween => tween.at() < self.elapsed,
+        })
+    }
+}
@@ -1,13 +1,20 @@
-use super::resources::CutsceneTime;
 use crate::{
+    components::GBColor,
+    core::time::DeltaTime,
+    game::resources::{Difficulty, GameFlags},
     layer::Layer,
     letterbox::events::LetterboxMove,
-    plugins::movement::linear::components::{
-        LinearMovementBundle, TargetingPositionX, TargetingPositionY,
+    plugins::movement::{
+        linear::components::{
+            LinearMovementAcceleratedBundle, LinearMovementBundle, TargetingPositionX,
+            TargetingPositionY,
+        },
+        track::{Easing, Path, Track, TweenPath},
     },
     stage::data::GAME_BASE_SPEED,
+    transitions::data::TransitionData,
 };
-use bevy::prelude::*;
+use bevy::{ecs::system::EntityCommands, prelude::*};
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DurationSecondsWithFrac};
@@ -31,38 +38,164 @@ impl Default for CutsceneLayer {
 }
 
 #[serde_as]
-#[derive(Clone, Copy, Debug, Default, Deserialize, Reflect, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Reflect, Serialize)]
 pub struct TargetMovement {
     pub position: Vec2,
     #[serde(default)]
     pub speed: f32,
+    /**
+     * Added to the speed every second, ignored when easing
+     */
     #[serde(default)]
     pub acceleration: f32,
+    /**
+     * Takes precedence over the speed and acceleration
+     */
+    #[serde_as(as = "Option<DurationSecondsWithFrac>")]
+    #[serde(default)]
+    pub duration: Option<Duration>,
+    #[serde(default)]
+    pub easing: Easing,
+    #[serde(default)]
+    pub path: Option<Path>,
 }
 
 impl TargetMovement {
-    pub fn make_bundles(
-        self,
+    /**
+     * Whether the straight linear bundles can describe the movement
+     */
+    pub fn is_linear(&self) -> bool {
+        self.path.is_none() && self.duration.is_none() && self.easing == Easing::Linear
+    }
+
+    pub fn track_from(&self, start: Vec2) -> Track {
+        let points = match &self.path {
+            Some(path) => path.sample(start, self.position),
+            None => vec![start, self.position],
+        };
+        let acceleration = match self.easing {
+            Easing::Linear if self.acceleration != 0. => Some(self.acceleration * GAME_BASE_SPEED),
+            _ => None,
+        };
+        Track::new(
+            points,
+            self.speed * GAME_BASE_SPEED,
+            acceleration,
+            self.easing,
+            self.duration,
+        )
+    }
+
+    /**
+     * Follows the sampled track, without going through the linear movement plugins
+     */
+    pub fn insert_tween<T: DeltaTime + Send + Sync + 'static>(
+        &self,
+        entity_commands: &mut EntityCommands,
         coordinates: Vec2,
-    ) -> (
-        LinearMovementBundle<CutsceneTime, TargetingPositionX>,
-        LinearMovementBundle<CutsceneTime, TargetingPositionY>,
     ) {
+        entity_commands.insert((
+            TargetingPositionX::new(coordinates.x),
+            TargetingPositionY::new(coordinates.y),
+            TweenPath::<T>::new(self.track_from(coordinates)),
+        ));
+    }
+
+    /**
+     * Generic over the clock, so that the same spawns can play in stages
+     */
+    pub fn insert_bundles<T: DeltaTime + Send + Sync + 'static>(
+        &self,
+        entity_commands: &mut EntityCommands,
+        coordinates: Vec2,
+    ) {
+        if !self.is_linear() {
+            self.insert_tween::<T>(entity_commands, coordinates);
+            return;
+        }
+
         let normalised_direction = (self.position - coordinates).normalize_or_zero();
         let velocity = normalised_direction * self.speed * GAME_BASE_SPEED;
 
-        (
-            LinearMovementBundle::<CutsceneTime, TargetingPositionX>::new(
-                coordinates.x,
-                self.position.x,
-                velocity.x,
-            ),
-            LinearMovementBundle::<CutsceneTime, TargetingPositionY>::new(
-                coordinates.y,
-                self.position.y,
-                velocity.y,
-            ),
-        )
+        if self.acceleration != 0. {
+            let acceleration = normalised_direction * self.acceleration * GAME_BASE_SPEED;
+            entity_commands.insert((
+                LinearMovementAcceleratedBundle::<T, TargetingPositionX>::new(
+                    coordinates.x,
+                    self.position.x,
+                    velocity.x,
+                    acceleration.x,
+                ),
+                LinearMovementAcceleratedBundle::<T, TargetingPositionY>::new(
+                    coordinates.y,
+                    self.position.y,
+                    velocity.y,
+                    acceleration.y,
+                ),
+            ));
+        } else {
+            entity_commands.insert((
+                LinearMovementBundle::<T, TargetingPositionX>::new(
+                    coordinates.x,
+                    self.position.x,
+                    velocity.x,
+                ),
+                LinearMovementBundle::<T, TargetingPositionY>::new(
+                    coordinates.y,
+                    self.position.y,
+                    velocity.y,
+                ),
+            ));
+        }
+    }
+}
+
+/**
+ * Changes to a cutscene sprite, timed from the moment it spawns
+ */
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
+pub enum CutsceneTween {
+    /**
+     * Swaps the palette filter, None clears it
+     */
+    Filter {
+        #[serde_as(as = "DurationSecondsWithFrac")]
+        at: Duration,
+        color: Option<GBColor>,
+    },
+    /**
+     * Toggles the visibility every interval, for good when no duration is given
+     */
+    Blink {
+        #[serde_as(as = "DurationSecondsWithFrac")]
+        at: Duration,
+        #[serde_as(as = "DurationSecondsWithFrac")]
+        interval: Duration,
+        #[serde_as(as = "Option<DurationSecondsWithFrac>")]
+        #[serde(default)]
+        duration: Option<Duration>,
+    },
+    /**
+     * Swaps the animation the sprite plays
+     */
+    Animation {
+        #[serde_as(as = "DurationSecondsWithFrac")]
+        at: Duration,
+        image_path: String,
+        frame_count: usize,
+        #[serde_as(as = "DurationSecondsWithFrac")]
+        duration: Duration,
+    },
+}
+
+impl CutsceneTween {
+    pub fn at(&self) -> Duration {
+        match self {
+            CutsceneTween::Filter { at, .. }
+            | CutsceneTween::Blink { at, .. }
+            | CutsceneTween::Animation { at, .. } => *at,
+        }
     }
 }
 
@@ -83,6 +216,9 @@ pub struct CutsceneAnimationSpawn {
     #[new(default)]
     #[serde(default)]
     pub target_movement_o: Option<TargetMovement>,
+    #[new(default)]
+    #[serde(default)]
+    pub tweens: Vec<CutsceneTween>,
 }
 
 #[derive(new, Clone, Component, Debug, Deserialize, Reflect, Serialize)]
@@ -94,6 +230,15 @@ pub struct CutsceneAnimationsSpawn {
 #[derive(new, Clone, Debug, Component)]
 pub struct CutsceneAwaitInput;
 
+/**
+ * Holds the act, on top of its elapse, until the transition finishes
+ */
+#[derive(new, Clone, Debug, Component)]
+pub struct CutsceneAwaitTransition;
+
+#[derive(new, Clone, Debug, Component)]
+pub struct CutsceneAwaitLetterbox;
+
 #[derive(new, Clone, Debug, Component)]
 pub struct CutsceneElapse {
     pub duration: Duration,
@@ -111,6 +256,32 @@ pub struct CutsceneImageSpawn {
     #[new(default)]
     #[serde(default)]
     pub tag_o: Option<String>,
+    #[new(default)]
+    #[serde(default)]
+    pub tweens: Vec<CutsceneTween>,
+}
+
+#[derive(new, Clone, Component, Debug, Deserialize, Reflect, Serialize)]
+pub struct CutsceneDialogue {
+    #[new(default)]
+    #[serde(default)]
+    pub speaker: Option<String>,
+    pub lines: Vec<String>,
+    #[new(default)]
+    #[serde(default)]
+    pub portrait_path: Option<String>,
+}
+
+impl CutsceneDialogue {
+    pub fn with_portrait(mut self, path: String) -> Self {
+        self.portrait_path = Some(path);
+        self
+    }
+
+    pub fn with_speaker(mut self, speaker: String) -> Self {
+        self.speaker = Some(speaker);
+        self
+    }
 }
 
 #[derive(new, Clone, Component, Debug, Deserialize, Reflect, Serialize)]
@@ -126,21 +297,104 @@ impl CutsceneImagesSpawn {
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
+pub enum CutsceneCondition {
+    Flag(String),
+    NotFlag(String),
+    Difficulty(Difficulty),
+    ScoreAtLeast(u32),
+    /**
+     * Index of the option picked on the last choice
+     */
+    Choice(usize),
+}
+
+impl CutsceneCondition {
+    pub fn is_met(
+        &self,
+        flags: &GameFlags,
+        difficulty: &Difficulty,
+        score: u32,
+        choice_o: Option<usize>,
+    ) -> bool {
+        match self {
+            CutsceneCondition::Flag(flag) => flags.is_set(flag),
+            CutsceneCondition::NotFlag(flag) => !flags.is_set(flag),
+            CutsceneCondition::Difficulty(x) => x == difficulty,
+            CutsceneCondition::ScoreAtLeast(x) => score >= *x,
+            CutsceneCondition::Choice(x) => choice_o == Some(*x),
+        }
+    }
+}
+
+/**
+ * Without a condition, it always jumps
+ */
+#[derive(new, Clone, Debug, Deserialize, Reflect, Serialize)]
+pub struct CutsceneBranch {
+    pub goto: String,
+    #[new(default)]
+    #[serde(default)]
+    pub condition: Option<CutsceneCondition>,
+}
+
+impl CutsceneBranch {
+    pub fn when(mut self, condition: CutsceneCondition) -> Self {
+        self.condition = Some(condition);
+        self
+    }
+}
+
+/**
+ * The textbox fits three options
+ */
+#[derive(new, Clone, Component, Debug, Deserialize, Reflect, Serialize)]
+pub struct CutsceneChoice {
+    pub options: Vec<String>,
+}
+
 #[serde_as]
 #[derive(new, Clone, Debug, Deserialize, Reflect, Serialize)]
 pub struct CutsceneAct {
     #[new(default)]
     #[serde(default)]
     pub await_input: bool,
+    /**
+     * Holds the act, on top of its elapse, until the letterbox stops moving
+     */
+    #[new(default)]
+    #[serde(default)]
+    pub await_letterbox: bool,
+    /**
+     * Checked in order once the act is over, the first one met jumps to its label
+     */
+    #[new(default)]
+    #[serde(default)]
+    pub branches: Vec<CutsceneBranch>,
+    /**
+     * Shown in the textbox after the dialogue, if any, and holds the act until picked
+     */
+    #[new(default)]
+    #[serde(default)]
+    pub choice_o: Option<CutsceneChoice>,
     #[new(default)]
     #[serde(default)]
     pub despawn_entities: Vec<String>,
+    /**
+     * Holds the act until every line has been read through
+     */
+    #[new(default)]
+    #[serde(default)]
+    pub dialogue_o: Option<CutsceneDialogue>,
     #[new(default)]
     #[serde(default)]
     #[serde_as(as = "DurationSecondsWithFrac")]
     pub elapse: Duration,
     #[new(default)]
     #[serde(default)]
+    pub label_o: Option<String>,
+    #[new(default)]
+    #[serde(default)]
     pub letterbox_move_o: Option<LetterboxMove>,
     #[new(default)]
     #[serde(default)]
@@ -150,21 +404,54 @@ pub struct CutsceneAct {
     pub music_spawn_o: Option<CutsceneMusicSpawn>,
     #[new(default)]
     #[serde(default)]
+    pub set_flags: Vec<String>,
+    #[new(default)]
+    #[serde(default)]
     pub spawn_animations_o: Option<CutsceneAnimationsSpawn>,
     #[new(default)]
     #[serde(default)]
     pub spawn_images_o: Option<CutsceneImagesSpawn>,
     #[new(default)]
     #[serde(default)]
-    pub transition_o: Option<CutsceneTransition>,
+    pub transition_o: Option<TransitionData>,
 }
 
 impl CutsceneAct {
+    pub fn with_dialogue(mut self, dialogue: CutsceneDialogue) -> Self {
+        self.dialogue_o = Some(dialogue);
+        self
+    }
+
+    pub fn with_label(mut self, label: String) -> Self {
+        self.label_o = Some(label);
+        self
+    }
+
+    pub fn with_choice(mut self, choice: CutsceneChoice) -> Self {
+        self.choice_o = Some(choice);
+        self
+    }
+
+    pub fn add_branch(mut self, branch: CutsceneBranch) -> Self {
+        self.branches.push(branch);
+        self
+    }
+
+    pub fn set_flag(mut self, flag: String) -> Self {
+        self.set_flags.push(flag);
+        self
+    }
+
     pub fn move_letterbox(mut self, x: LetterboxMove) -> Self {
         self.letterbox_move_o = Some(x);
         self
     }
 
+    pub fn await_letterbox(mut self) -> Self {
+        self.await_letterbox = true;
+        self
+    }
+
     pub fn spawn_animations(mut self, spawns: CutsceneAnimationsSpawn) -> Self {
         self.spawn_animations_o = Some(spawns);
         self
@@ -181,7 +468,17 @@ impl CutsceneAct {
     }
 
     pub fn despawn_music(mut self) -> Self {
-        self.music_despawn_o = Some(CutsceneMusicDespawn {});
+        self.music_despawn_o = Some(CutsceneMusicDespawn::new());
+        self
+    }
+
+    pub fn fade_out_music(mut self, secs: f32) -> Self {
+        self.music_despawn_o = Some(CutsceneMusicDespawn::new().with_fade_out(secs));
+        self
+    }
+
+    pub fn with_transition(mut self, transition: TransitionData) -> Self {
+        self.transition_o = Some(transition);
         self
     }
 
@@ -191,15 +488,40 @@ impl CutsceneAct {
     }
 }
 
+#[serde_as]
 #[derive(new, Clone, Component, Debug, Deserialize, Reflect, Serialize)]
 pub struct CutsceneMusicSpawn {
     pub music_path: String,
-    // TODO fade_in
+    /**
+     * Also fades out the music already playing, crossfading into the new track
+     */
+    #[new(default)]
+    #[serde_as(as = "Option<DurationSecondsWithFrac>")]
+    #[serde(default)]
+    pub fade_in: Option<Duration>,
 }
 
-#[derive(new, Clone, Component, Debug, Deserialize, Reflect, Serialize)]
+impl CutsceneMusicSpawn {
+    pub fn with_fade_in(mut self, secs: f32) -> Self {
+        self.fade_in = Some(Duration::from_secs_f32(secs));
+        self
+    }
+}
+
+#[serde_as]
+#[derive(new, Clone, Component, Debug, Default, Deserialize, Reflect, Serialize)]
 pub struct CutsceneMusicDespawn {
-    // TODO fade_out
+    #[new(default)]
+    #[serde_as(as = "Option<DurationSecondsWithFrac>")]
+    #[serde(default)]
+    pub fade_out: Option<Duration>,
+}
+
+impl CutsceneMusicDespawn {
+    pub fn with_fade_out(mut self, secs: f32) -> Self {
+        self.fade_out = Some(Duration::from_secs_f32(secs));
+        self
+    }
 }
 
 #[derive(new, Clone, Component, Debug, Deserialize, Reflect, Serialize)]
@@ -210,9 +532,6 @@ pub struct CutsceneSpriteSpawn {
     pub tag_o: Option<String>,
 }
 
-#[derive(new, Clone, Component, Debug, Deserialize, Reflect, Serialize)]
-pub struct CutsceneTransition;
-
 #[derive(new, Asset, Clone, Debug, Deserialize, Reflect, Resource, Serialize)]
 pub struct CutsceneData {
     pub name: String,
@@ -225,4 +544,10 @@ impl CutsceneData {
         self.steps = steps;
         self
     }
+
+    pub fn find_label(&self, label: &str) -> Option<usize> {
+        self.steps
+            .iter()
+            .position(|act| act.label_o.as_deref() == Some(label))
+    }
 }
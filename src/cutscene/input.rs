@@ -1,16 +1,26 @@
-use crate::input::GBInput;
+use crate::input::{GBInput, GBInputBindings};
 use bevy::prelude::*;
 use bevy::reflect::Reflect;
+use leafwing_input_manager::action_state::ActionState;
 use leafwing_input_manager::Actionlike;
-use leafwing_input_manager::{action_state::ActionState, input_map::InputMap};
 
 #[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
 pub enum CutsceneInput {
+    Advance,
+    Down,
+    FastForward,
     Skip,
+    Up,
 }
 
-pub fn init_input(mut commands: Commands) {
-    let ms: Vec<(CutsceneInput, KeyCode)> = vec![(CutsceneInput::Skip, GBInput::Start.into())];
+pub fn init_input(mut commands: Commands, bindings: Res<GBInputBindings>) {
+    let ms: Vec<(CutsceneInput, GBInput)> = vec![
+        (CutsceneInput::Advance, GBInput::A),
+        (CutsceneInput::Down, GBInput::Down),
+        (CutsceneInput::FastForward, GBInput::B),
+        (CutsceneInput::Up, GBInput::Up),
+        (CutsceneInput::Skip, GBInput::Start),
+    ];
     commands.insert_resource(ActionState::<CutsceneInput>::default());
-    commands.insert_resource(InputMap::<CutsceneInput>::new(ms));
+    commands.insert_resource(bindings.make_input_map(ms));
 }
@@ -3,24 +3,37 @@ pub mod data;
 pub mod events;
 pub mod input;
 pub mod resources;
-mod systems;
+pub mod systems;
 
 use self::{
     events::{CutsceneShutdownTrigger, CutsceneStartupTrigger},
     input::{init_input, CutsceneInput},
     resources::CutsceneTime,
     systems::{
-        interactions::check_press_start_input,
+        dialogue::{
+            process_cutscene_choice_spawn, process_cutscene_dialogue_spawn, update_cutscene_choice,
+            update_cutscene_dialogue,
+        },
+        interactions::{
+            blink_await_input_indicator, check_await_input, check_press_start_input,
+            check_skip_prompt_timeout, update_fast_forward,
+        },
         progress::*,
         setup::{on_cutscene_shutdown, on_cutscene_startup},
+        tweens::update_sprite_tweens,
     },
 };
 use crate::{
-    core::{event::on_trigger_write_event, time::tick_time},
+    core::{
+        event::on_trigger_write_event,
+        time::{tick_time, TimeMultiplier},
+    },
+    input::GBInputBindings,
     plugins::movement::linear::{
         components::{TargetingPositionX, TargetingPositionY},
         LinearMovementPlugin,
     },
+    systems::{audio::update_volume_fade, tween::update_tween_path},
 };
 use bevy::prelude::*;
 use bevy_common_assets::ron::RonAssetPlugin;
@@ -37,13 +50,19 @@ impl Plugin for CutscenePlugin {
             .add_plugins(LinearMovementPlugin::<CutsceneTime, TargetingPositionY>::default())
             .init_state::<CutscenePluginUpdateState>()
             .init_resource::<CutsceneTime>()
+            .insert_resource(TimeMultiplier::<CutsceneTime>::new(1.))
             .add_event::<CutsceneStartupTrigger>()
             .observe(on_cutscene_startup)
             .add_event::<CutsceneShutdownTrigger>()
             .observe(on_cutscene_shutdown)
             .observe(on_trigger_write_event::<CutsceneShutdownTrigger>)
+            .observe(on_transition_finished)
+            .observe(on_letterbox_move_finished)
             // .add_systems(OnEnter(CutscenePluginUpdateState::Active), spawn_cutscene)
-            .add_systems(Startup, init_input)
+            .add_systems(
+                PreUpdate,
+                init_input.run_if(resource_changed::<GBInputBindings>),
+            )
             .add_systems(
                 Update,
                 (
@@ -55,11 +74,22 @@ impl Plugin for CutscenePlugin {
                             process_cutscene_images_spawn,
                             process_cutscene_music_spawn,
                             process_cutscene_music_despawn,
+                            process_cutscene_dialogue_spawn,
+                            update_cutscene_dialogue,
+                            process_cutscene_choice_spawn,
+                            update_cutscene_choice,
+                            check_await_input,
+                            blink_await_input_indicator,
+                            check_skip_prompt_timeout,
                         ),
                     )
                         .chain(),
                     // render_cutscene,
+                    update_fast_forward.before(tick_time::<CutsceneTime>),
                     tick_time::<CutsceneTime>,
+                    update_volume_fade::<CutsceneTime>,
+                    update_tween_path::<CutsceneTime>,
+                    update_sprite_tweens::<CutsceneTime>,
                 )
                     .run_if(in_state(CutscenePluginUpdateState::Active)),
             )
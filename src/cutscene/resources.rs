@@ -27,7 +27,11 @@ impl Ticker for CutsceneTime {
     }
 }
 
-#[derive(Resource, Default, Clone, Copy)]
+#[derive(Resource, Default, Clone, Copy, Debug)]
 pub struct CutsceneProgress {
     pub index: usize,
+    /**
+     * Option picked on the last choice shown
+     */
+    pub last_choice: Option<usize>,
 }
@@ -0,0 +1,318 @@
+use crate::{
+    components::{AudioSystemBundle, AudioSystemType, GBColor, VolumeSettings},
+    cutscene::{
+        components::{
+            Cinematic, CutsceneChoiceOption, CutsceneChoiceProgress, CutsceneDialogueProgress,
+            CutsceneDialogueText, CutsceneEntity, CutsceneTextbox, CutsceneTypingSfx,
+        },
+        data::{CutsceneChoice, CutsceneDialogue, CutsceneLayer},
+        input::CutsceneInput,
+        resources::{CutsceneProgress, CutsceneTime},
+    },
+    globals::{
+        mark_for_despawn_by_query, FONT_SIZE, SCREEN_RESOLUTION, TYPEFACE_CHARACTERS, TYPEFACE_PATH,
+    },
+    layer::Layer,
+    pixel::components::PxRectangle,
+};
+use assert_assets_path::assert_assets_path;
+use bevy::{audio::PlaybackMode, prelude::*};
+use leafwing_input_manager::prelude::ActionState;
+use seldom_pixel::{
+    prelude::{PxAnchor, PxAssets, PxCanvas, PxSubPosition, PxText, PxTextBundle, PxTypeface},
+    sprite::{PxSprite, PxSpriteBundle},
+};
+
+const TEXTBOX_HEIGHT: u32 = 40;
+const TEXTBOX_MARGIN: i32 = 4;
+const TEXTBOX_PORTRAIT_SIZE: i32 = 32;
+
+fn spawn_typing_sfx(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    volume_settings: &Res<VolumeSettings>,
+) {
+    commands.spawn((
+        Name::new("TypingSfx"),
+        CutsceneEntity,
+        CutsceneTypingSfx,
+        AudioBundle {
+            source: asset_server.load(assert_assets_path!("audio/sfx/typing_message.ogg")),
+            settings: PlaybackSettings {
+                mode: PlaybackMode::Loop,
+                volume: volume_settings.sfx.clone(),
+                ..default()
+            },
+            ..default()
+        },
+        AudioSystemBundle {
+            system_type: AudioSystemType::SFX,
+        },
+    ));
+}
+
+fn spawn_textbox_background(commands: &mut Commands) {
+    commands.spawn((
+        Name::new("Textbox"),
+        CutsceneEntity,
+        CutsceneTextbox,
+        PxSubPosition(Vec2::ZERO),
+        PxRectangle {
+            anchor: PxAnchor::BottomLeft,
+            canvas: PxCanvas::Camera,
+            color: GBColor::Black,
+            width: SCREEN_RESOLUTION.x,
+            height: TEXTBOX_HEIGHT,
+            layer: Layer::CutsceneLayer(CutsceneLayer::Textbox),
+        },
+    ));
+}
+
+fn spawn_textbox(
+    commands: &mut Commands,
+    assets_sprite: &mut PxAssets<PxSprite>,
+    typefaces: &mut PxAssets<PxTypeface>,
+    dialogue: &CutsceneDialogue,
+) {
+    let typeface = typefaces.load(TYPEFACE_PATH, TYPEFACE_CHARACTERS, [(' ', 4)]);
+    let text_layer = Layer::CutsceneLayer(CutsceneLayer::Text);
+    let screen_width = SCREEN_RESOLUTION.x as i32;
+    let top = TEXTBOX_HEIGHT as i32 - TEXTBOX_MARGIN;
+
+    spawn_textbox_background(commands);
+
+    let mut text_left = TEXTBOX_MARGIN;
+    if let Some(portrait_path) = &dialogue.portrait_path {
+        commands.spawn((
+            Name::new("TextboxPortrait"),
+            CutsceneEntity,
+            CutsceneTextbox,
+            PxSpriteBundle::<Layer> {
+                sprite: assets_sprite.load(portrait_path.clone()),
+                anchor: PxAnchor::BottomLeft,
+                canvas: PxCanvas::Camera,
+                layer: text_layer.clone(),
+                ..default()
+            },
+            PxSubPosition(Vec2::new(TEXTBOX_MARGIN as f32, TEXTBOX_MARGIN as f32)),
+        ));
+        text_left += TEXTBOX_PORTRAIT_SIZE + TEXTBOX_MARGIN;
+    }
+
+    let mut body_top = top;
+    if let Some(speaker) = &dialogue.speaker {
+        body_top -= FONT_SIZE as i32;
+        commands.spawn((
+            Name::new("TextboxSpeaker"),
+            CutsceneEntity,
+            CutsceneTextbox,
+            PxTextBundle::<Layer> {
+                alignment: PxAnchor::TopLeft,
+                canvas: PxCanvas::Camera,
+                layer: text_layer.clone(),
+                rect: IRect::new(text_left, body_top, screen_width - TEXTBOX_MARGIN, top).into(),
+                text: speaker.clone().into(),
+                typeface: typeface.clone(),
+                ..default()
+            },
+        ));
+    }
+
+    commands.spawn((
+        Name::new("TextboxText"),
+        CutsceneEntity,
+        CutsceneTextbox,
+        CutsceneDialogueText,
+        PxTextBundle::<Layer> {
+            alignment: PxAnchor::TopLeft,
+            canvas: PxCanvas::Camera,
+            layer: text_layer,
+            rect: IRect::new(
+                text_left,
+                TEXTBOX_MARGIN,
+                screen_width - TEXTBOX_MARGIN,
+                body_top,
+            )
+            .into(),
+            text: "".into(),
+            typeface,
+            ..default()
+        },
+    ));
+}
+
+pub fn process_cutscene_dialogue_spawn(
+    mut commands: Commands,
+    query: Query<(Entity, &CutsceneDialogue), (With<Cinematic>, Added<CutsceneDialogue>)>,
+    mut assets_sprite: PxAssets<PxSprite>,
+    mut typefaces: PxAssets<PxTypeface>,
+    asset_server: Res<AssetServer>,
+    volume_settings: Res<VolumeSettings>,
+    time: Res<CutsceneTime>,
+) {
+    for (entity, dialogue) in query.iter() {
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.remove::<CutsceneDialogue>();
+
+        if dialogue.lines.is_empty() {
+            continue;
+        }
+
+        entity_commands.insert(CutsceneDialogueProgress::new(
+            dialogue.clone(),
+            time.elapsed,
+        ));
+        spawn_textbox(&mut commands, &mut assets_sprite, &mut typefaces, dialogue);
+        spawn_typing_sfx(&mut commands, &asset_server, &volume_settings);
+    }
+}
+
+/**
+ * Types the current line out, A reveals the rest of it or moves on to the next
+ */
+pub fn update_cutscene_dialogue(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut CutsceneDialogueProgress), With<Cinematic>>,
+    mut text_query: Query<&mut PxText, With<CutsceneDialogueText>>,
+    textbox_query: Query<Entity, With<CutsceneTextbox>>,
+    sfx_query: Query<Entity, With<CutsceneTypingSfx>>,
+    input: Res<ActionState<CutsceneInput>>,
+    asset_server: Res<AssetServer>,
+    volume_settings: Res<VolumeSettings>,
+    time: Res<CutsceneTime>,
+) {
+    for (entity, mut progress) in query.iter_mut() {
+        if input.just_pressed(&CutsceneInput::Advance) {
+            if !progress.is_line_revealed(time.elapsed) {
+                progress.line_completed = true;
+            } else if progress.next_line(time.elapsed).is_some() {
+                spawn_typing_sfx(&mut commands, &asset_server, &volume_settings);
+            } else {
+                commands.entity(entity).remove::<CutsceneDialogueProgress>();
+                mark_for_despawn_by_query(&mut commands, &textbox_query);
+                mark_for_despawn_by_query(&mut commands, &sfx_query);
+                continue;
+            }
+        }
+
+        if progress.is_line_revealed(time.elapsed) {
+            mark_for_despawn_by_query(&mut commands, &sfx_query);
+        }
+
+        if let Some(line) = progress.current_line() {
+            let revealed: String = line
+                .chars()
+                .take(progress.revealed_count(time.elapsed))
+                .collect();
+            for mut text in text_query.iter_mut() {
+                if text.0 != revealed {
+                    text.0 = revealed.clone();
+                }
+            }
+        }
+    }
+}
+
+fn choice_option_text(option: &str, selected: bool) -> String {
+    if selected {
+        format!("> {}", option)
+    } else {
+        format!("  {}", option)
+    }
+}
+
+/**
+ * Waits on the dialogue of the act, if any, to be read through
+ */
+pub fn process_cutscene_choice_spawn(
+    mut commands: Commands,
+    query: Query<
+        (Entity, &CutsceneChoice),
+        (
+            With<Cinematic>,
+            Without<CutsceneDialogue>,
+            Without<CutsceneDialogueProgress>,
+        ),
+    >,
+    mut typefaces: PxAssets<PxTypeface>,
+) {
+    for (entity, choice) in query.iter() {
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.remove::<CutsceneChoice>();
+
+        if choice.options.is_empty() {
+            continue;
+        }
+
+        entity_commands.insert(CutsceneChoiceProgress {
+            options: choice.options.clone(),
+            selected: 0,
+        });
+
+        let typeface = typefaces.load(TYPEFACE_PATH, TYPEFACE_CHARACTERS, [(' ', 4)]);
+        let top = TEXTBOX_HEIGHT as i32 - TEXTBOX_MARGIN;
+
+        spawn_textbox_background(&mut commands);
+
+        for (i, option) in choice.options.iter().enumerate() {
+            let option_top = top - (i as i32 * FONT_SIZE as i32);
+            commands.spawn((
+                Name::new("TextboxChoiceOption"),
+                CutsceneEntity,
+                CutsceneTextbox,
+                CutsceneChoiceOption(i),
+                PxTextBundle::<Layer> {
+                    alignment: PxAnchor::TopLeft,
+                    canvas: PxCanvas::Camera,
+                    layer: Layer::CutsceneLayer(CutsceneLayer::Text),
+                    rect: IRect::new(
+                        TEXTBOX_MARGIN,
+                        option_top - FONT_SIZE as i32,
+                        SCREEN_RESOLUTION.x as i32 - TEXTBOX_MARGIN,
+                        option_top,
+                    )
+                    .into(),
+                    text: choice_option_text(option, i == 0).into(),
+                    typeface: typeface.clone(),
+                    ..default()
+                },
+            ));
+        }
+    }
+}
+
+/**
+ * Up and Down move the cursor, A picks the option for the branches to check
+ */
+pub fn update_cutscene_choice(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut CutsceneChoiceProgress), With<Cinematic>>,
+    mut option_query: Query<(&CutsceneChoiceOption, &mut PxText)>,
+    textbox_query: Query<Entity, With<CutsceneTextbox>>,
+    input: Res<ActionState<CutsceneInput>>,
+    mut progress: ResMut<CutsceneProgress>,
+) {
+    for (entity, mut choice) in query.iter_mut() {
+        if input.just_pressed(&CutsceneInput::Advance) {
+            progress.last_choice = Some(choice.selected);
+            commands.entity(entity).remove::<CutsceneChoiceProgress>();
+            mark_for_despawn_by_query(&mut commands, &textbox_query);
+            continue;
+        }
+
+        let count = choice.options.len();
+        if input.just_pressed(&CutsceneInput::Up) {
+            choice.selected = (choice.selected + count - 1) % count;
+        } else if input.just_pressed(&CutsceneInput::Down) {
+            choice.selected = (choice.selected + 1) % count;
+        } else {
+            continue;
+        }
+
+        for (option, mut text) in option_query.iter_mut() {
+            if let Some(label) = choice.options.get(option.0) {
+                text.0 = choice_option_text(label, option.0 == choice.selected);
+            }
+        }
+    }
+}
@@ -1,9 +1,174 @@
-use crate::cutscene::{events::CutsceneShutdownTrigger, input::CutsceneInput};
+use crate::{
+    components::{CutsceneElapsedStarted, DespawnMark},
+    core::time::TimeMultiplier,
+    cutscene::{
+        components::{
+            Cinematic, CutsceneAwaitIndicator, CutsceneDialogueProgress, CutsceneEntity,
+            CutsceneSkipPrompt, AWAIT_INDICATOR_BLINK_SECS, FAST_FORWARD_MULTIPLIER,
+            SKIP_CONFIRM_SECS,
+        },
+        data::{CutsceneAwaitInput, CutsceneElapse, CutsceneLayer},
+        events::CutsceneShutdownTrigger,
+        input::CutsceneInput,
+        resources::CutsceneTime,
+    },
+    globals::{
+        mark_for_despawn_by_query, FONT_SIZE, SCREEN_RESOLUTION, TYPEFACE_CHARACTERS, TYPEFACE_PATH,
+    },
+    layer::Layer,
+};
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::ActionState;
+use seldom_pixel::prelude::{PxAnchor, PxAssets, PxCanvas, PxTextBundle, PxTypeface};
+use std::time::Duration;
 
-pub fn check_press_start_input(mut commands: Commands, gb_input: Res<ActionState<CutsceneInput>>) {
-    if gb_input.just_pressed(&CutsceneInput::Skip) {
+const AWAIT_INDICATOR_MARGIN: i32 = 4;
+
+fn spawn_skip_prompt(
+    commands: &mut Commands,
+    typefaces: &mut PxAssets<PxTypeface>,
+    shown: Duration,
+) {
+    let typeface = typefaces.load(TYPEFACE_PATH, TYPEFACE_CHARACTERS, [(' ', 4)]);
+    let top = SCREEN_RESOLUTION.y as i32 - AWAIT_INDICATOR_MARGIN;
+
+    commands.spawn((
+        Name::new("SkipPrompt"),
+        CutsceneEntity,
+        CutsceneSkipPrompt { shown },
+        PxTextBundle::<Layer> {
+            alignment: PxAnchor::TopRight,
+            canvas: PxCanvas::Camera,
+            layer: Layer::CutsceneLayer(CutsceneLayer::Overtext(0)),
+            rect: IRect::new(
+                AWAIT_INDICATOR_MARGIN,
+                top - FONT_SIZE as i32,
+                SCREEN_RESOLUTION.x as i32 - AWAIT_INDICATOR_MARGIN,
+                top,
+            )
+            .into(),
+            text: "START to skip".into(),
+            typeface,
+            ..default()
+        },
+    ));
+}
+
+/**
+ * Skipping takes a second press of Start while the prompt is up
+ */
+pub fn check_press_start_input(
+    mut commands: Commands,
+    prompt_query: Query<Entity, With<CutsceneSkipPrompt>>,
+    mut typefaces: PxAssets<PxTypeface>,
+    gb_input: Res<ActionState<CutsceneInput>>,
+    time: Res<Time>,
+) {
+    if !gb_input.just_pressed(&CutsceneInput::Skip) {
+        return;
+    }
+    if prompt_query.is_empty() {
+        spawn_skip_prompt(&mut commands, &mut typefaces, time.elapsed());
+    } else {
+        mark_for_despawn_by_query(&mut commands, &prompt_query);
         commands.trigger(CutsceneShutdownTrigger);
     }
 }
+
+/**
+ * Runs on the app clock, so fast-forwarding doesn't shorten the window
+ */
+pub fn check_skip_prompt_timeout(
+    mut commands: Commands,
+    query: Query<(Entity, &CutsceneSkipPrompt)>,
+    time: Res<Time>,
+) {
+    for (entity, prompt) in query.iter() {
+        if prompt.shown + Duration::from_secs_f32(SKIP_CONFIRM_SECS) <= time.elapsed() {
+            commands.entity(entity).insert(DespawnMark);
+        }
+    }
+}
+
+pub fn update_fast_forward(
+    input: Res<ActionState<CutsceneInput>>,
+    mut time_multiplier: ResMut<TimeMultiplier<CutsceneTime>>,
+) {
+    let value = if input.pressed(&CutsceneInput::FastForward) {
+        FAST_FORWARD_MULTIPLIER
+    } else {
+        1.
+    };
+    if time_multiplier.value != value {
+        time_multiplier.value = value;
+    }
+}
+
+pub fn spawn_await_input_indicator(commands: &mut Commands, typefaces: &mut PxAssets<PxTypeface>) {
+    let typeface = typefaces.load(TYPEFACE_PATH, TYPEFACE_CHARACTERS, [(' ', 4)]);
+    let right = SCREEN_RESOLUTION.x as i32 - AWAIT_INDICATOR_MARGIN;
+
+    commands.spawn((
+        Name::new("AwaitIndicator"),
+        CutsceneEntity,
+        CutsceneAwaitIndicator,
+        PxTextBundle::<Layer> {
+            alignment: PxAnchor::BottomRight,
+            canvas: PxCanvas::Camera,
+            layer: Layer::CutsceneLayer(CutsceneLayer::Overtext(0)),
+            rect: IRect::new(
+                right - FONT_SIZE as i32,
+                AWAIT_INDICATOR_MARGIN,
+                right,
+                AWAIT_INDICATOR_MARGIN + FONT_SIZE as i32,
+            )
+            .into(),
+            text: ">".into(),
+            typeface,
+            ..default()
+        },
+    ));
+}
+
+/**
+ * Dialogue takes the A presses for itself until it is done
+ */
+pub fn check_await_input(
+    mut commands: Commands,
+    query: Query<
+        Entity,
+        (
+            With<Cinematic>,
+            With<CutsceneAwaitInput>,
+            Without<CutsceneDialogueProgress>,
+        ),
+    >,
+    indicator_query: Query<Entity, With<CutsceneAwaitIndicator>>,
+    input: Res<ActionState<CutsceneInput>>,
+) {
+    if !input.just_pressed(&CutsceneInput::Advance) {
+        return;
+    }
+    for entity in query.iter() {
+        commands
+            .entity(entity)
+            .remove::<CutsceneAwaitInput>()
+            .remove::<CutsceneElapse>()
+            .remove::<CutsceneElapsedStarted>();
+        mark_for_despawn_by_query(&mut commands, &indicator_query);
+    }
+}
+
+pub fn blink_await_input_indicator(
+    mut query: Query<&mut Visibility, With<CutsceneAwaitIndicator>>,
+    time: Res<CutsceneTime>,
+) {
+    let visible = (time.elapsed.as_secs_f32() / AWAIT_INDICATOR_BLINK_SECS) as u32 % 2 == 0;
+    for mut visibility in query.iter_mut() {
+        *visibility = if visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
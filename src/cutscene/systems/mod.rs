@@ -1,3 +1,5 @@
+pub mod dialogue;
 pub mod interactions;
 pub mod progress;
 pub mod setup;
+pub mod tweens;
@@ -1,25 +1,70 @@
-use crate::components::VolumeSettings;
 use crate::{
-    components::{Cleared, CutsceneElapsedStarted, Music, Tag},
+    components::{
+        Cleared, CutsceneElapsedStarted, DespawnMark, Music, Tag, VolumeFade, VolumeLevel,
+        VolumeSettings,
+    },
     cutscene::{
-        components::{Cinematic, CutsceneEntity, CutsceneGraphic},
+        components::{
+            Cinematic, CutsceneAwaitIndicator, CutsceneChoiceProgress, CutsceneDialogueProgress,
+            CutsceneEntity, CutsceneGraphic, SpriteTweens,
+        },
         data::*,
         events::CutsceneShutdownTrigger,
         resources::{CutsceneProgress, CutsceneTime},
+        systems::interactions::spawn_await_input_indicator,
+    },
+    game::{
+        resources::{Difficulty, GameFlags},
+        score::components::Score,
     },
     globals::mark_for_despawn_by_query,
     layer::Layer,
-    letterbox::events::LetterboxMoveTrigger,
-    systems::spawn::make_music_bundle,
+    letterbox::events::{LetterboxMoveFinishedTrigger, LetterboxMoveTrigger},
+    resources::DifficultySelected,
+    systems::spawn::{make_faded_music_bundle, make_music_bundle},
+    transitions::events::{TransitionFinishedTrigger, TransitionSource, TransitionStartupTrigger},
 };
 use bevy::{audio::PlaybackMode, prelude::*};
 use seldom_pixel::{
     prelude::{
         PxAnchor, PxAnimationBundle, PxAnimationDuration, PxAnimationFinishBehavior, PxAssets,
-        PxSubPosition,
+        PxSubPosition, PxTypeface,
     },
     sprite::{PxSprite, PxSpriteBundle},
 };
+use std::time::Duration;
+
+/**
+ * Follows the first branch met on the act just finished, otherwise carries on to the next one
+ */
+fn follow_branches(
+    progress: &mut CutsceneProgress,
+    data: &CutsceneData,
+    flags: &GameFlags,
+    difficulty: &Difficulty,
+    score: u32,
+) {
+    let Some(act) = progress
+        .index
+        .checked_sub(1)
+        .and_then(|index| data.steps.get(index))
+    else {
+        return;
+    };
+
+    let branch_o = act.branches.iter().find(|branch| {
+        branch.condition.as_ref().map_or(true, |condition| {
+            condition.is_met(flags, difficulty, score, progress.last_choice)
+        })
+    });
+
+    if let Some(branch) = branch_o {
+        match data.find_label(&branch.goto) {
+            Some(index) => progress.index = index,
+            None => warn!("Cutscene label not found: {}", branch.goto),
+        }
+    }
+}
 
 pub fn read_step_trigger(
     mut commands: Commands,
@@ -29,16 +74,36 @@ pub fn read_step_trigger(
         (
             With<Cinematic>,
             Without<CutsceneElapsedStarted>,
+            Without<CutsceneDialogueProgress>,
+            Without<CutsceneChoice>,
+            Without<CutsceneChoiceProgress>,
             Without<Cleared>,
         ),
     >,
+    tagged_query: Query<(Entity, &Tag), With<CutsceneGraphic>>,
+    mut typefaces: PxAssets<PxTypeface>,
+    mut flags: ResMut<GameFlags>,
     data: Res<CutsceneData>,
+    difficulty: Res<DifficultySelected>,
+    score: Res<Score>,
     time: Res<CutsceneTime>,
 ) {
     for entity in query.iter() {
+        follow_branches(&mut progress, &data, &flags, &difficulty.0, score.value);
+
         if let Some(act) = data.steps.get(progress.index) {
             progress.index += 1;
 
+            for flag in act.set_flags.iter() {
+                flags.set(flag.clone());
+            }
+
+            for (tagged_entity, tag) in tagged_query.iter() {
+                if act.despawn_entities.contains(&tag.0) {
+                    commands.entity(tagged_entity).insert(DespawnMark);
+                }
+            }
+
             if let Some(x) = &act.letterbox_move_o {
                 commands.trigger(LetterboxMoveTrigger::from(x.clone()));
             }
@@ -50,6 +115,12 @@ pub fn read_step_trigger(
                 CutsceneElapsedStarted(time.elapsed),
             ));
 
+            if let Some(x) = &act.choice_o {
+                entity_commands.insert(x.clone());
+            }
+            if let Some(x) = &act.dialogue_o {
+                entity_commands.insert(x.clone());
+            }
             if let Some(x) = &act.music_despawn_o {
                 entity_commands.insert(x.clone());
             }
@@ -63,7 +134,22 @@ pub fn read_step_trigger(
                 entity_commands.insert(x.clone());
             }
             if act.await_input {
-                // TODO
+                entity_commands.insert(CutsceneAwaitInput::new());
+                spawn_await_input_indicator(&mut commands, &mut typefaces);
+            }
+            if act.await_letterbox && act.letterbox_move_o.is_some() {
+                commands
+                    .entity(entity)
+                    .insert(CutsceneAwaitLetterbox::new());
+            }
+            if let Some(x) = &act.transition_o {
+                commands
+                    .entity(entity)
+                    .insert(CutsceneAwaitTransition::new());
+                commands.trigger(TransitionStartupTrigger {
+                    data: x.clone(),
+                    source: TransitionSource::Cutscene,
+                });
             }
         } else {
             let mut entity_commands = commands.entity(entity);
@@ -73,18 +159,40 @@ pub fn read_step_trigger(
     }
 }
 
+/**
+ * An act awaiting input without an elapse only moves on with A
+ */
 pub fn check_cutscene_elapsed(
     mut commands: Commands,
-    query: Query<(Entity, &CutsceneElapsedStarted, &CutsceneElapse), With<Cinematic>>,
+    query: Query<
+        (
+            Entity,
+            &CutsceneElapsedStarted,
+            &CutsceneElapse,
+            Has<CutsceneAwaitInput>,
+        ),
+        (
+            With<Cinematic>,
+            Without<CutsceneAwaitLetterbox>,
+            Without<CutsceneAwaitTransition>,
+        ),
+    >,
     cutscene_query: Query<Entity, With<CutsceneGraphic>>,
+    indicator_query: Query<Entity, With<CutsceneAwaitIndicator>>,
     time: Res<CutsceneTime>,
 ) {
-    for (entity, started, elapse) in query.iter() {
+    for (entity, started, elapse, awaiting_input) in query.iter() {
+        if awaiting_input && elapse.duration.is_zero() {
+            continue;
+        }
         if started.0 + elapse.duration < time.elapsed {
             commands
                 .entity(entity)
                 .remove::<CutsceneElapse>()
-                .remove::<CutsceneElapsedStarted>();
+                .remove::<CutsceneElapsedStarted>()
+                .remove::<CutsceneAwaitInput>();
+
+            mark_for_despawn_by_query(&mut commands, &indicator_query);
 
             if elapse.clear_graphics {
                 mark_for_despawn_by_query(&mut commands, &cutscene_query);
@@ -93,6 +201,29 @@ pub fn check_cutscene_elapsed(
     }
 }
 
+pub fn on_transition_finished(
+    trigger: Trigger<TransitionFinishedTrigger>,
+    mut commands: Commands,
+    query: Query<Entity, (With<Cinematic>, With<CutsceneAwaitTransition>)>,
+) {
+    if trigger.event().source != TransitionSource::Cutscene {
+        return;
+    }
+    for entity in query.iter() {
+        commands.entity(entity).remove::<CutsceneAwaitTransition>();
+    }
+}
+
+pub fn on_letterbox_move_finished(
+    _trigger: Trigger<LetterboxMoveFinishedTrigger>,
+    mut commands: Commands,
+    query: Query<Entity, (With<Cinematic>, With<CutsceneAwaitLetterbox>)>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).remove::<CutsceneAwaitLetterbox>();
+    }
+}
+
 pub fn process_cutscene_animations_spawn(
     mut commands: Commands,
     query: Query<
@@ -129,7 +260,12 @@ pub fn process_cutscene_animations_spawn(
             }
 
             if let Some(target_movement) = &spawn.target_movement_o {
-                entity_commands.insert(target_movement.make_bundles(spawn.coordinates.clone()));
+                target_movement
+                    .insert_bundles::<CutsceneTime>(&mut entity_commands, spawn.coordinates);
+            }
+
+            if !spawn.tweens.is_empty() {
+                entity_commands.insert(SpriteTweens::<CutsceneTime>::new(spawn.tweens.clone()));
             }
         }
 
@@ -161,30 +297,68 @@ pub fn process_cutscene_images_spawn(
             if let Some(tag) = &spawn.tag_o {
                 entity_commands.insert(Tag(tag.clone()));
             }
+
+            if !spawn.tweens.is_empty() {
+                entity_commands.insert(SpriteTweens::<CutsceneTime>::new(spawn.tweens.clone()));
+            }
         }
 
         commands.entity(entity).remove::<CutsceneImagesSpawn>();
     }
 }
 
+/**
+ * Fades the music out when given a duration, otherwise stops it right away
+ */
+fn stop_music(
+    commands: &mut Commands,
+    music_query: &Query<(Entity, &VolumeLevel), With<Music>>,
+    fade_out_o: Option<Duration>,
+) {
+    for (entity, level) in music_query.iter() {
+        match fade_out_o {
+            Some(fade_out) => {
+                commands
+                    .entity(entity)
+                    .insert(VolumeFade::<CutsceneTime>::fade_out(level.0, fade_out));
+            }
+            None => {
+                commands.entity(entity).insert(DespawnMark);
+            }
+        }
+    }
+}
+
 pub fn process_cutscene_music_spawn(
     mut commands: Commands,
     query: Query<(Entity, &CutsceneMusicSpawn), (With<Cinematic>, Added<CutsceneMusicSpawn>)>,
-    music_query: Query<Entity, With<Music>>,
+    music_query: Query<(Entity, &VolumeLevel), With<Music>>,
     asset_server: Res<AssetServer>,
     volume_settings: Res<VolumeSettings>,
 ) {
     for (entity, spawn) in query.iter() {
-        mark_for_despawn_by_query(&mut commands, &music_query);
+        stop_music(&mut commands, &music_query, spawn.fade_in);
 
-        let music_bundle = make_music_bundle(
-            &asset_server,
-            &volume_settings,
-            spawn.music_path.to_string(),
-            PlaybackMode::Loop,
-        );
+        let mut entity_commands = commands.spawn((CutsceneEntity, Name::new("Cutscene music")));
+        if let Some(fade_in) = spawn.fade_in {
+            entity_commands.insert((
+                make_faded_music_bundle(
+                    &asset_server,
+                    &volume_settings,
+                    spawn.music_path.to_string(),
+                    PlaybackMode::Loop,
+                ),
+                VolumeFade::<CutsceneTime>::fade_in(fade_in),
+            ));
+        } else {
+            entity_commands.insert(make_music_bundle(
+                &asset_server,
+                &volume_settings,
+                spawn.music_path.to_string(),
+                PlaybackMode::Loop,
+            ));
+        }
 
-        commands.spawn((CutsceneEntity, music_bundle, Name::new("Cutscene music")));
         commands.entity(entity).remove::<CutsceneMusicSpawn>();
     }
 }
@@ -192,10 +366,10 @@ pub fn process_cutscene_music_spawn(
 pub fn process_cutscene_music_despawn(
     mut commands: Commands,
     query: Query<(Entity, &CutsceneMusicDespawn), (With<Cinematic>, Added<CutsceneMusicDespawn>)>,
-    music_query: Query<Entity, With<Music>>,
+    music_query: Query<(Entity, &VolumeLevel), With<Music>>,
 ) {
     for (entity, despawn) in query.iter() {
-        mark_for_despawn_by_query(&mut commands, &music_query);
+        stop_music(&mut commands, &music_query, despawn.fade_out);
         commands.entity(entity).remove::<CutsceneMusicDespawn>();
     }
 }
@@ -1,9 +1,10 @@
 use crate::{
+    core::time::TimeMultiplier,
     cutscene::{
         components::{Cinematic, CutsceneEntity},
         data::CutsceneData,
         events::{CutsceneShutdownTrigger, CutsceneStartupTrigger},
-        resources::CutsceneProgress,
+        resources::{CutsceneProgress, CutsceneTime},
         CutscenePluginUpdateState,
     },
     debug::plugin::{debug_print_shutdown, debug_print_startup},
@@ -18,15 +19,18 @@ pub fn on_cutscene_startup(
     trigger: Trigger<CutsceneStartupTrigger>,
     mut commands: Commands,
     mut next_state: ResMut<NextState<CutscenePluginUpdateState>>,
+    mut time_multiplier: ResMut<TimeMultiplier<CutsceneTime>>,
 ) {
     #[cfg(debug_assertions)]
     debug_print_startup(DEBUG_MODULE);
 
+    time_multiplier.value = 1.;
+
     let e = trigger.event();
     next_state.set(CutscenePluginUpdateState::Active);
 
     commands.insert_resource::<CutsceneData>(e.data.as_ref().clone());
-    commands.insert_resource::<CutsceneProgress>(CutsceneProgress { index: 0 });
+    commands.insert_resource::<CutsceneProgress>(CutsceneProgress::default());
 
     commands.spawn((Cinematic, Name::new("Cutscene")));
 }
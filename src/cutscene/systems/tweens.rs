@@ -0,0 +1,85 @@
+use crate::{
+    components::PxSpriteColorLoader,
+    core::time::DeltaTime,
+    cutscene::{components::SpriteTweens, data::CutsceneTween},
+};
+use bevy::prelude::*;
+use seldom_pixel::{
+    prelude::{
+        PxAnimationBundle, PxAnimationDuration, PxAnimationFinishBehavior, PxAssets, PxFilter,
+    },
+    sprite::PxSprite,
+};
+use std::time::Duration;
+
+pub fn update_sprite_tweens<T: DeltaTime + Resource>(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut SpriteTweens<T>, &mut Visibility)>,
+    mut assets_sprite: PxAssets<PxSprite>,
+    mut filters: PxAssets<PxFilter>,
+    time: Res<T>,
+) {
+    for (entity, mut tweens, mut visibility) in query.iter_mut() {
+        let previous = tweens.elapsed;
+        tweens.elapsed += time.delta();
+        let elapsed = tweens.elapsed;
+        let crossed = |at: Duration| previous <= at && at < elapsed;
+
+        for tween in tweens.tweens.iter() {
+            match tween {
+                CutsceneTween::Filter { at, color } if crossed(*at) => match color {
+                    Some(color) => {
+                        commands.entity(entity).insert(filters.load_color(*color));
+                    }
+                    None => {
+                        commands.entity(entity).remove::<Handle<PxFilter>>();
+                    }
+                },
+                CutsceneTween::Animation {
+                    at,
+                    image_path,
+                    frame_count,
+                    duration,
+                } if crossed(*at) => {
+                    commands.entity(entity).insert((
+                        assets_sprite.load_animated(image_path.clone(), *frame_count),
+                        PxAnimationBundle {
+                            duration: PxAnimationDuration::millis_per_animation(
+                                duration.as_millis() as u64,
+                            ),
+                            on_finish: PxAnimationFinishBehavior::Loop,
+                            ..default()
+                        },
+                    ));
+                }
+                CutsceneTween::Blink {
+                    at,
+                    interval,
+                    duration,
+                } if *at <= elapsed => {
+                    let end_o = duration.map(|duration| *at + duration);
+                    let next = match end_o {
+                        Some(end) if end <= elapsed => Visibility::Inherited,
+                        _ if interval.is_zero() => Visibility::Inherited,
+                        _ => {
+                            let phase = (elapsed - *at).as_secs_f32() / interval.as_secs_f32();
+                            if phase as u32 % 2 == 0 {
+                                Visibility::Inherited
+                            } else {
+                                Visibility::Hidden
+                            }
+                        }
+                    };
+                    if *visibility != next {
+                        *visibility = next;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if tweens.is_finished() {
+            commands.entity(entity).remove::<SpriteTweens<T>>();
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use leafwing_input_manager::Actionlike;
+use leafwing_input_manager::{action_state::ActionState, input_map::InputMap};
+
+/**
+ * Debug-only actions, kept out of GBInput so that they are never rebindable nor shipped
+ */
+#[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
+pub enum DebugInput {
+    DUp,
+    DDown,
+    DLeft,
+    DRight,
+    DToGame,
+    DToMainMenu,
+    DExit,
+}
+
+pub fn init_debug_input(mut commands: Commands) {
+    let ms: Vec<(DebugInput, KeyCode)> = vec![
+        (DebugInput::DToGame, KeyCode::KeyI),
+        (DebugInput::DToMainMenu, KeyCode::Delete),
+        (DebugInput::DExit, KeyCode::Escape),
+        (DebugInput::DLeft, KeyCode::KeyA),
+        (DebugInput::DUp, KeyCode::KeyW),
+        (DebugInput::DRight, KeyCode::KeyD),
+        (DebugInput::DDown, KeyCode::KeyS),
+    ];
+    commands.insert_resource(ActionState::<DebugInput>::default());
+    commands.insert_resource(InputMap::<DebugInput>::new(ms));
+}
@@ -1,15 +1,27 @@
+#[cfg(debug_assertions)]
+pub mod input;
 pub mod plugin;
 mod systems;
 pub mod types;
 
 use self::{systems::*, types::register_types};
 use bevy::prelude::*;
+#[cfg(debug_assertions)]
+use {
+    self::input::{init_debug_input, DebugInput},
+    crate::systems::camera::{move_camera, update_camera},
+    leafwing_input_manager::plugin::InputManagerPlugin,
+};
 
 pub struct DebugPlugin;
 
 impl Plugin for DebugPlugin {
     fn build(&self, app: &mut App) {
         register_types(app);
+        #[cfg(debug_assertions)]
+        app.add_plugins(InputManagerPlugin::<DebugInput>::default())
+            .add_systems(Startup, init_debug_input)
+            .add_systems(Update, move_camera.before(update_camera));
         app.init_state::<DebugPluginUpdateState>().add_systems(
             Update,
             (draw_floor_lines, draw_colliders).run_if(in_state(DebugPluginUpdateState::Active)),
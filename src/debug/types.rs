@@ -12,8 +12,9 @@ use crate::{
         components::{
             damage::{DamageFlicker, InflictsDamage},
             interactive::{ColliderData, Health},
+            movement::CameraPathMovement,
             placement::{Depth, Floor, RailPosition, Speed},
-            CurrentStageStep, StageElapsedStarted,
+            CinematicProgress, CurrentStageStep, StageElapsedStarted,
         },
         enemy::{
             components::{
@@ -28,14 +29,16 @@ use crate::{
                 EnemyTardigrade, EnemyTardigradeAnimation, EnemyTardigradeAttacking,
             },
         },
-        pickup::components::HealthRecovery,
+        pickup::components::{HealthRecovery, PickupCollection, PickupFall, PickupLifetime},
         player::components::{CameraShake, PlayerAttack},
         resources::StageTime,
     },
 };
 
 pub fn register_types(app: &mut App) {
-    app.register_type::<CameraShake>()
+    app.register_type::<CameraPathMovement>()
+        .register_type::<CameraShake>()
+        .register_type::<CinematicProgress>()
         .register_type::<CircleAround>()
         .register_type::<ColliderData>()
         .register_type::<CurrentEnemyMosquitoStep>()
@@ -61,6 +64,9 @@ pub fn register_types(app: &mut App) {
         .register_type::<GBColor>()
         .register_type::<Layer>()
         .register_type::<LinearMovement>()
+        .register_type::<PickupCollection>()
+        .register_type::<PickupFall>()
+        .register_type::<PickupLifetime>()
         .register_type::<PlayerAttack>()
         .register_type::<RailPosition>()
         .register_type::<Speed>()
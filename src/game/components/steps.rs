@@ -1,4 +1,6 @@
-use crate::{cutscene::data::CutsceneData, stage::data::StageData};
+use crate::{
+    cutscene::data::CutsceneData, stage::data::StageData, transitions::data::TransitionData,
+};
 use bevy::prelude::*;
 use derive_new::new;
 use std::sync::Arc;
@@ -18,13 +20,20 @@ pub struct CinematicAssetGameStep {
     pub is_checkpoint: bool,
 }
 
-#[derive(Component, Clone, Debug)]
-pub struct CreditsGameStep;
+/**
+ * Rolls the credits from a credits.ron asset, going back to the main menu if it's the last step
+ */
+#[derive(new, Component, Clone, Debug)]
+pub struct CreditsGameStep {
+    pub src: String,
+}
 
-#[derive(Component, Clone, Debug)]
+/**
+ * Moves on to the next step once the transition finishes
+ */
+#[derive(new, Component, Clone, Debug)]
 pub struct TransitionGameStep {
-    // TODO
-    // pub transition: bool,
+    pub data: TransitionData,
 }
 
 #[derive(new, Component, Clone, Debug)]
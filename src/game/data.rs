@@ -3,6 +3,7 @@ use derive_more::From;
 
 pub const STARTING_LIVES: u8 = 3;
 pub const DEATH_SCORE_PENALTY: i32 = 150;
+pub const CONTINUE_SCORE_PENALTY: i32 = 500;
 
 #[derive(Clone, Debug, From)]
 pub enum GameStep {
@@ -8,3 +8,21 @@ pub struct GameOverTrigger {
 
 #[derive(Event)]
 pub struct GameStartupTrigger;
+
+/**
+ * Tears the stage down and starts the current step over
+ */
+#[derive(Event)]
+pub struct GameRestartStepTrigger;
+
+/**
+ * Spends score to restart the current step after a game over
+ */
+#[derive(Event)]
+pub struct GameContinueTrigger;
+
+/**
+ * Tears the game down and goes back to the main menu
+ */
+#[derive(Event)]
+pub struct GameReturnToTitleTrigger;
@@ -5,11 +5,11 @@ pub mod resources;
 pub mod score;
 mod systems;
 
-use crate::core::event::on_trigger_write_event;
+use crate::{core::event::on_trigger_write_event, transitions::TransitionPluginUpdateState};
 
 use self::{events::*, resources::GameProgress, score::ScorePlugin, systems::setup::*};
 use bevy::prelude::*;
-use resources::{CutsceneAssetHandle, StageAssetHandle};
+use resources::{CreditsAssetHandle, CutsceneAssetHandle, GameFlags, StageAssetHandle};
 use systems::debug::debug_on_game_over;
 
 pub struct GamePlugin;
@@ -19,19 +19,30 @@ impl Plugin for GamePlugin {
         app.add_plugins(ScorePlugin)
             .init_state::<GamePluginUpdateState>()
             .init_state::<GameProgressState>()
+            .init_resource::<GameFlags>()
             .add_event::<GameOverTrigger>()
             .observe(on_game_over)
             .observe(on_trigger_write_event::<GameOverTrigger>)
             .add_event::<GameStartupTrigger>()
             .observe(on_game_startup)
+            .add_event::<GameContinueTrigger>()
+            .observe(on_game_continue)
+            .add_event::<GameRestartStepTrigger>()
+            .observe(on_game_restart_step)
+            .add_event::<GameReturnToTitleTrigger>()
+            .observe(on_game_return_to_title)
+            .observe(on_transition_finished)
             .add_systems(
                 Update,
                 ((
+                    check_credits_data_loaded.run_if(resource_exists::<CreditsAssetHandle>),
                     check_cutscene_data_loaded.run_if(resource_exists::<CutsceneAssetHandle>),
                     check_stage_data_loaded.run_if(resource_exists::<StageAssetHandle>),
-                    progress,
+                    progress.run_if(in_state(TransitionPluginUpdateState::Inactive)),
                     on_stage_cleared,
                     on_cutscene_shutdown,
+                    on_credits_shutdown,
+                    save_game_progress,
                 )
                     .run_if(resource_exists::<GameProgress>),)
                     .run_if(in_state(GamePluginUpdateState::Active)),
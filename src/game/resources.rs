@@ -1,9 +1,10 @@
-use crate::{cutscene::data::CutsceneData, stage::data::StageData};
+use crate::{credits::data::CreditsData, cutscene::data::CutsceneData, stage::data::StageData};
 
-use super::data::GameStep;
+use super::data::{GameStep, STARTING_LIVES};
 use bevy::prelude::*;
 use num_enum::TryFromPrimitive;
-use std::iter::Step;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, iter::Step};
 use strum_macros::EnumIter;
 
 #[derive(Resource, Debug, Default, Clone, Copy)]
@@ -14,6 +15,48 @@ pub struct GameProgress {
     pub index: usize,
 }
 
+pub const GAME_SAVE_FILE: &str = "game.ron";
+
+/**
+ * Story flags raised by stages and cutscenes, for the cutscenes to branch on
+ */
+#[derive(Resource, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GameFlags {
+    pub flags: HashSet<String>,
+}
+
+impl GameFlags {
+    pub fn set(&mut self, flag: String) {
+        self.flags.insert(flag);
+    }
+
+    pub fn is_set(&self, flag: &str) -> bool {
+        self.flags.contains(flag)
+    }
+}
+
+/**
+ * Written every time the game moves on to its next step, and read back when a game starts
+ */
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GameSave {
+    pub index: usize,
+    pub flags: GameFlags,
+    pub lives: u8,
+    pub score: u32,
+}
+
+impl Default for GameSave {
+    fn default() -> Self {
+        Self {
+            index: 0,
+            flags: GameFlags::default(),
+            lives: STARTING_LIVES,
+            score: 0,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Resource)]
 pub struct GameData {
     pub name: String,
@@ -30,7 +73,10 @@ pub struct GameData {
     PartialOrd,
     Hash,
     Default,
+    Deserialize,
     EnumIter,
+    Reflect,
+    Serialize,
     TryFromPrimitive,
 )]
 #[repr(i8)]
@@ -57,6 +103,11 @@ impl Step for Difficulty {
     }
 }
 
+#[derive(Resource)]
+pub struct CreditsAssetHandle {
+    pub handle: Handle<CreditsData>,
+}
+
 #[derive(Resource)]
 pub struct CutsceneAssetHandle {
     pub handle: Handle<CutsceneData>,
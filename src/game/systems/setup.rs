@@ -1,6 +1,11 @@
 use std::sync::Arc;
 
 use crate::{
+    core::save::{load_save, write_save},
+    credits::{
+        data::CreditsData,
+        events::{CreditsShutdownTrigger, CreditsStartupTrigger},
+    },
     cutscene::{
         data::CutsceneData,
         events::{CutsceneShutdownTrigger, CutsceneStartupTrigger},
@@ -8,20 +13,36 @@ use crate::{
     },
     debug::plugin::{debug_print_shutdown, debug_print_startup},
     game::{
-        components::steps::*, data::*, events::GameStartupTrigger, resources::*, GameOverTrigger,
-        GamePluginUpdateState,
+        components::steps::*,
+        data::*,
+        events::{
+            GameContinueTrigger, GameRestartStepTrigger, GameReturnToTitleTrigger,
+            GameStartupTrigger,
+        },
+        resources::*,
+        score::components::Score,
+        GameOverTrigger, GamePluginUpdateState,
     },
+    main_menu::events::MainMenuStartupEvent,
     progression::game::GAME_DATA,
     stage::{
+        catalogue::ObjectCatalogueParam,
         data::StageData,
-        events::{StageClearedTrigger, StageStartupTrigger},
+        events::{StageClearedTrigger, StageShutdownTrigger, StageStartupTrigger},
         StagePluginUpdateState,
     },
+    transitions::events::{
+        TransitionFinishedTrigger, TransitionShutdownTrigger, TransitionSource,
+        TransitionStartupTrigger,
+    },
 };
 use bevy::prelude::*;
 
 const DEBUG_MODULE: &str = "Game";
 
+/**
+ * Picks up from the saved step, flags, lives and score, unless the saved game already ran to its end
+ */
 pub fn on_game_startup(
     _trigger: Trigger<GameStartupTrigger>,
     mut next_state: ResMut<NextState<GamePluginUpdateState>>,
@@ -30,10 +51,17 @@ pub fn on_game_startup(
     #[cfg(debug_assertions)]
     debug_print_startup(DEBUG_MODULE);
 
+    let save = match load_save::<GameSave>(GAME_SAVE_FILE) {
+        Some(save) if save.index < GAME_DATA.steps.len() => save,
+        _ => GameSave::default(),
+    };
+
     next_state.set(GamePluginUpdateState::Active);
-    commands.insert_resource::<GameProgress>(GameProgress { index: 0 });
+    commands.insert_resource::<GameProgress>(GameProgress { index: save.index });
     commands.insert_resource::<GameData>(GAME_DATA.clone());
-    commands.insert_resource(Lives(STARTING_LIVES));
+    commands.insert_resource(Lives(save.lives));
+    commands.insert_resource(save.flags);
+    commands.insert_resource(Score { value: save.score });
 }
 
 // pub fn on_game_shutdown(
@@ -48,6 +76,68 @@ pub fn on_game_startup(
 
 pub fn on_game_over(_trigger: Trigger<GameOverTrigger>) {}
 
+/**
+ * Restarts the current step, which is the stage that was lost
+ */
+pub fn on_game_continue(
+    _trigger: Trigger<GameContinueTrigger>,
+    mut commands: Commands,
+    mut lives: ResMut<Lives>,
+    mut score: ResMut<Score>,
+) {
+    score.add(-CONTINUE_SCORE_PENALTY);
+    lives.0 = STARTING_LIVES;
+    commands.trigger(GameRestartStepTrigger);
+}
+
+/**
+ * Flagging the progress as changed has it start the current step again
+ */
+pub fn on_game_restart_step(
+    _trigger: Trigger<GameRestartStepTrigger>,
+    mut commands: Commands,
+    progress: Option<ResMut<GameProgress>>,
+) {
+    commands.trigger(StageShutdownTrigger);
+
+    if let Some(mut progress) = progress {
+        progress.set_changed();
+    }
+}
+
+/**
+ * Leaving on a game over, without continuing, forfeits the saved game
+ */
+pub fn on_game_return_to_title(
+    _trigger: Trigger<GameReturnToTitleTrigger>,
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<GamePluginUpdateState>>,
+    cutscene_state: Res<State<CutscenePluginUpdateState>>,
+    lives: Res<Lives>,
+) {
+    #[cfg(debug_assertions)]
+    debug_print_shutdown(DEBUG_MODULE);
+
+    if 0 == lives.0 {
+        write_save(GAME_SAVE_FILE, &GameSave::default());
+    }
+
+    commands.trigger(StageShutdownTrigger);
+    if *cutscene_state.get() == CutscenePluginUpdateState::Active {
+        commands.trigger(CutsceneShutdownTrigger);
+    }
+    commands.trigger(TransitionShutdownTrigger);
+
+    next_state.set(GamePluginUpdateState::Inactive);
+    commands.remove_resource::<GameProgress>();
+    commands.remove_resource::<CutsceneData>();
+    commands.remove_resource::<CreditsAssetHandle>();
+    commands.remove_resource::<CutsceneAssetHandle>();
+    commands.remove_resource::<StageAssetHandle>();
+
+    commands.trigger(MainMenuStartupEvent);
+}
+
 pub fn on_stage_cleared(
     mut event_reader: EventReader<StageClearedTrigger>,
     mut commands: Commands,
@@ -75,6 +165,41 @@ pub fn on_cutscene_shutdown(
     }
 }
 
+/**
+ * Once the last step is over the game goes back to the main menu
+ */
+pub fn on_credits_shutdown(
+    mut event_reader: EventReader<CreditsShutdownTrigger>,
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<GamePluginUpdateState>>,
+    mut progress: ResMut<GameProgress>,
+    game_data: Res<GameData>,
+    flags: Res<GameFlags>,
+    lives: Res<Lives>,
+    score: Res<Score>,
+) {
+    for _ in event_reader.read() {
+        progress.index += 1;
+        commands.remove_resource::<CreditsData>();
+
+        if progress.index >= game_data.steps.len() {
+            // GameProgress is gone before save_game_progress sees it, the next game starts over
+            write_save(
+                GAME_SAVE_FILE,
+                &GameSave {
+                    index: progress.index,
+                    flags: flags.clone(),
+                    lives: lives.0,
+                    score: score.value,
+                },
+            );
+            next_state.set(GamePluginUpdateState::Inactive);
+            commands.remove_resource::<GameProgress>();
+            commands.trigger(MainMenuStartupEvent);
+        }
+    }
+}
+
 pub fn progress(
     asset_server: Res<AssetServer>,
     game_progress: Res<GameProgress>,
@@ -86,8 +211,10 @@ pub fn progress(
     if game_progress.is_added() || game_progress.is_changed() {
         if let Some(data) = game_data.steps.get(game_progress.index) {
             match data {
-                GameStep::Credits(CreditsGameStep {}) => {
-                    // TODO
+                GameStep::Credits(CreditsGameStep { src }) => {
+                    commands.insert_resource(CreditsAssetHandle {
+                        handle: asset_server.load::<CreditsData>(src),
+                    });
                 }
                 GameStep::Cutscene(CutsceneGameStep {
                     data,
@@ -109,14 +236,69 @@ pub fn progress(
                         handle: asset_server.load::<StageData>(src),
                     });
                 }
-                GameStep::Transition(TransitionGameStep {}) => {
-                    // TODO
+                GameStep::Transition(TransitionGameStep { data }) => {
+                    commands.trigger(TransitionStartupTrigger {
+                        data: data.clone(),
+                        source: TransitionSource::GameStep,
+                    });
                 }
             }
         }
     }
 }
 
+pub fn save_game_progress(
+    game_progress: Res<GameProgress>,
+    flags: Res<GameFlags>,
+    lives: Res<Lives>,
+    score: Res<Score>,
+) {
+    if game_progress.is_changed() {
+        write_save(
+            GAME_SAVE_FILE,
+            &GameSave {
+                index: game_progress.index,
+                flags: flags.clone(),
+                lives: lives.0,
+                score: score.value,
+            },
+        );
+    }
+}
+
+pub fn on_transition_finished(
+    trigger: Trigger<TransitionFinishedTrigger>,
+    game_data: Option<Res<GameData>>,
+    progress: Option<ResMut<GameProgress>>,
+) {
+    if trigger.event().source != TransitionSource::GameStep {
+        return;
+    }
+    if let (Some(game_data), Some(mut progress)) = (game_data, progress) {
+        if let Some(GameStep::Transition(_)) = game_data.steps.get(progress.index) {
+            progress.index += 1;
+        }
+    }
+}
+
+pub fn check_credits_data_loaded(
+    asset_handle: Res<CreditsAssetHandle>,
+    data_assets: Res<Assets<CreditsData>>,
+    mut commands: Commands,
+) {
+    if let Some(data) = data_assets.get(&asset_handle.handle) {
+        #[cfg(debug_assertions)]
+        println!("Credits data loaded: {:?}", data);
+        commands.remove_resource::<CreditsAssetHandle>();
+        commands.trigger(CreditsStartupTrigger {
+            data: Arc::new(data.clone()),
+        });
+    } else {
+        #[cfg(debug_assertions)]
+        println!("Credits data is still loading...");
+    }
+}
+
 pub fn check_cutscene_data_loaded(
     asset_handle: Res<CutsceneAssetHandle>,
     data_assets: Res<Assets<CutsceneData>>,
@@ -136,12 +318,16 @@ pub fn check_cutscene_data_loaded(
     }
 }
 
+/**
+ * Also waits on the object catalogue, which the stage spawns reference
+ */
 pub fn check_stage_data_loaded(
     asset_handle: Res<StageAssetHandle>,
     data_assets: Res<Assets<StageData>>,
+    catalogue: ObjectCatalogueParam,
     mut commands: Commands,
 ) {
-    if let Some(data) = data_assets.get(&asset_handle.handle) {
+    if let (Some(data), Some(_)) = (data_assets.get(&asset_handle.handle), catalogue.get()) {
         #[cfg(debug_assertions)]
         println!("Stage data loaded: {:?}", data);
         commands.remove_resource::<StageAssetHandle>();
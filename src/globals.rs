@@ -60,6 +60,23 @@ pub fn is_inside_area(position: Vec2, bottom_left: Vec2, top_right: Vec2) -> boo
         && position.y <= top_right.y
 }
 
+/**
+ * Maps a logical window position (origin at the top-left) into the screen canvas,
+ * which is scaled to fit the window keeping its aspect ratio and centered on it.
+ * Returns None when the position falls outside of the canvas.
+ */
+pub fn window_to_screen_position(window_size: Vec2, position: Vec2) -> Option<Vec2> {
+    let scale = (window_size / *SCREEN_RESOLUTION_F32).min_element();
+    let offset = (window_size - *SCREEN_RESOLUTION_F32 * scale) / 2.;
+    let screen_position = (position - offset) / scale;
+    let screen_position = Vec2::new(
+        screen_position.x,
+        SCREEN_RESOLUTION_F32.y - screen_position.y,
+    );
+
+    is_inside_area(screen_position, Vec2::ZERO, *SCREEN_RESOLUTION_F32).then_some(screen_position)
+}
+
 // TODO could replace with a generic trigger/observe?
 pub fn mark_for_despawn_by_query<F: QueryFilter>(
     commands: &mut Commands,
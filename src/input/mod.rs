@@ -0,0 +1,118 @@
+use crate::core::save::write_save;
+use bevy::{prelude::*, reflect::Reflect};
+use leafwing_input_manager::{input_map::InputMap, Actionlike};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum_macros::EnumIter;
+
+pub const INPUT_BINDINGS_FILE: &str = "input.ron";
+
+#[derive(
+    Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Deserialize, EnumIter, Reflect, Serialize,
+)]
+pub enum GBInput {
+    A,
+    B,
+    Up,
+    Down,
+    Left,
+    Right,
+    Start,
+    Select,
+}
+
+/**
+ * Keyboard and gamepad bindings for each of the GB buttons.
+ * Every other input map is derived from these, so rebinding a button applies everywhere.
+ */
+#[derive(Clone, Debug, Deserialize, Resource, Serialize)]
+pub struct GBInputBindings {
+    pub keys: HashMap<GBInput, KeyCode>,
+    pub buttons: HashMap<GBInput, GamepadButtonType>,
+}
+
+impl Default for GBInputBindings {
+    fn default() -> Self {
+        Self {
+            keys: HashMap::from([
+                (GBInput::A, KeyCode::KeyX),
+                (GBInput::B, KeyCode::KeyZ),
+                (GBInput::Up, KeyCode::ArrowUp),
+                (GBInput::Down, KeyCode::ArrowDown),
+                (GBInput::Left, KeyCode::ArrowLeft),
+                (GBInput::Right, KeyCode::ArrowRight),
+                (GBInput::Start, KeyCode::Enter),
+                (GBInput::Select, KeyCode::ShiftRight),
+            ]),
+            buttons: HashMap::from([
+                (GBInput::A, GamepadButtonType::East),
+                (GBInput::B, GamepadButtonType::South),
+                (GBInput::Up, GamepadButtonType::DPadUp),
+                (GBInput::Down, GamepadButtonType::DPadDown),
+                (GBInput::Left, GamepadButtonType::DPadLeft),
+                (GBInput::Right, GamepadButtonType::DPadRight),
+                (GBInput::Start, GamepadButtonType::Start),
+                (GBInput::Select, GamepadButtonType::Select),
+            ]),
+        }
+    }
+}
+
+impl GBInputBindings {
+    pub fn key(&self, action: GBInput) -> Option<KeyCode> {
+        self.keys.get(&action).copied()
+    }
+
+    pub fn button(&self, action: GBInput) -> Option<GamepadButtonType> {
+        self.buttons.get(&action).copied()
+    }
+
+    /**
+     * Binds the key to the action, swapping it with whichever action was already using it
+     */
+    pub fn bind_key(&mut self, action: GBInput, key: KeyCode) {
+        rebind(&mut self.keys, action, key);
+    }
+
+    /**
+     * Binds the button to the action, swapping it with whichever action was already using it
+     */
+    pub fn bind_button(&mut self, action: GBInput, button: GamepadButtonType) {
+        rebind(&mut self.buttons, action, button);
+    }
+
+    /**
+     * Builds an input map for a screen-specific action set out of the GB buttons it listens to
+     */
+    pub fn make_input_map<A: Actionlike>(&self, ms: Vec<(A, GBInput)>) -> InputMap<A> {
+        let mut input_map = InputMap::<A>::default();
+        for (action, input) in ms {
+            if let Some(key) = self.key(input) {
+                input_map.insert(action.clone(), key);
+            }
+            if let Some(button) = self.button(input) {
+                input_map.insert(action, button);
+            }
+        }
+        input_map
+    }
+
+    pub fn save(&self) {
+        write_save(INPUT_BINDINGS_FILE, self);
+    }
+}
+
+fn rebind<T: Copy + PartialEq>(map: &mut HashMap<GBInput, T>, action: GBInput, value: T) {
+    let previous = map.insert(action, value);
+    let conflict = map
+        .iter()
+        .find(|(other, x)| **other != action && **x == value)
+        .map(|(other, _)| *other);
+
+    if let Some(other) = conflict {
+        match previous {
+            Some(previous) => map.insert(other, previous),
+            None => map.remove(&other),
+        };
+    }
+}
@@ -1,4 +1,6 @@
+use crate::plugins::movement::track::Easing;
 use bevy::prelude::*;
+use std::time::Duration;
 
 #[derive(Component)]
 pub struct LetterboxEntity;
@@ -13,3 +15,49 @@ pub const LETTERBOX_NORMAL_SPEED: f32 = 10.;
 pub const LETTERBOX_INSTANT_SPEED: f32 = f32::MAX;
 
 pub const LETTERBOX_HEIGHT: u32 = 30;
+
+/**
+ * Eases a bar from one position to another over a fixed duration
+ */
+#[derive(Clone, Component, Debug)]
+pub struct LetterboxMovement {
+    pub from: f32,
+    pub to: f32,
+    pub easing: Easing,
+    pub duration: Duration,
+    pub elapsed: Duration,
+}
+
+impl LetterboxMovement {
+    /**
+     * Without a fixed duration, it is derived from the speed
+     */
+    pub fn new(from: f32, to: f32, speed: f32, easing: Easing, duration: Option<Duration>) -> Self {
+        let duration = duration.unwrap_or_else(|| {
+            if speed > 0. {
+                Duration::from_secs_f32((to - from).abs() / speed)
+            } else {
+                Duration::ZERO
+            }
+        });
+        Self {
+            from,
+            to,
+            easing,
+            duration,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    pub fn position(&self) -> f32 {
+        if self.duration.is_zero() {
+            return self.to;
+        }
+        let t = (self.elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.);
+        self.from + (self.to - self.from) * self.easing.apply(t)
+    }
+}
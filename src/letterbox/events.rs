@@ -1,45 +1,92 @@
+use super::components::{LETTERBOX_HEIGHT, LETTERBOX_INSTANT_SPEED, LETTERBOX_NORMAL_SPEED};
+use crate::plugins::movement::track::Easing;
 use bevy::prelude::*;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DurationSecondsWithFrac};
+use std::time::Duration;
 
-use super::components::{LETTERBOX_HEIGHT, LETTERBOX_INSTANT_SPEED, LETTERBOX_NORMAL_SPEED};
-
+/**
+ * Targets are the height each bar covers, a missing one leaves that bar where it is
+ */
 #[derive(new, Clone, Debug, Deserialize, Event, Serialize)]
 pub struct LetterboxMoveTrigger {
     pub speed: f32,
-    pub target: f32,
+    pub top: Option<f32>,
+    pub bottom: Option<f32>,
+    #[new(default)]
+    pub easing: Easing,
+    /**
+     * Takes precedence over the speed
+     */
+    #[new(default)]
+    pub duration: Option<Duration>,
 }
 
 impl LetterboxMoveTrigger {
     pub fn open() -> Self {
-        Self::new(LETTERBOX_NORMAL_SPEED, LETTERBOX_HEIGHT as f32)
+        Self::move_to_at(LETTERBOX_HEIGHT as f32, LETTERBOX_NORMAL_SPEED)
     }
 
     pub fn close() -> Self {
-        Self::new(LETTERBOX_NORMAL_SPEED, 0.0)
+        Self::move_to_at(0.0, LETTERBOX_NORMAL_SPEED)
     }
 
     pub fn show() -> Self {
-        Self::new(LETTERBOX_INSTANT_SPEED, LETTERBOX_HEIGHT as f32)
+        Self::move_to_at(LETTERBOX_HEIGHT as f32, LETTERBOX_INSTANT_SPEED)
     }
 
     pub fn hide() -> Self {
-        Self::new(LETTERBOX_INSTANT_SPEED, 0.0)
+        Self::move_to_at(0.0, LETTERBOX_INSTANT_SPEED)
     }
 
     pub fn move_to(target: f32) -> Self {
-        Self::new(LETTERBOX_NORMAL_SPEED, target)
+        Self::move_to_at(target, LETTERBOX_NORMAL_SPEED)
     }
 
     pub fn move_to_at(target: f32, speed: f32) -> Self {
-        Self::new(speed, target)
+        Self::new(speed, Some(target), Some(target))
+    }
+
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
     }
 }
 
+/**
+ * Sent once both bars have reached their targets
+ */
+#[derive(Clone, Debug, Event)]
+pub struct LetterboxMoveFinishedTrigger;
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
+pub struct LetterboxTween {
+    #[serde(default)]
+    pub top: Option<f32>,
+    #[serde(default)]
+    pub bottom: Option<f32>,
+    #[serde_as(as = "DurationSecondsWithFrac")]
+    pub duration: Duration,
+    #[serde(default)]
+    pub easing: Easing,
+}
+
 #[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
 pub enum LetterboxMove {
     To(f32),
     ToAt(f32, f32),
+    /**
+     * Top and bottom targets, at the normal speed
+     */
+    Split(f32, f32),
+    Tween(LetterboxTween),
     Hide,
     Show,
     Close,
@@ -51,6 +98,14 @@ impl From<LetterboxMove> for LetterboxMoveTrigger {
         match x {
             LetterboxMove::To(target) => LetterboxMoveTrigger::move_to(target),
             LetterboxMove::ToAt(target, speed) => LetterboxMoveTrigger::move_to_at(target, speed),
+            LetterboxMove::Split(top, bottom) => {
+                LetterboxMoveTrigger::new(LETTERBOX_NORMAL_SPEED, Some(top), Some(bottom))
+            }
+            LetterboxMove::Tween(tween) => {
+                LetterboxMoveTrigger::new(LETTERBOX_NORMAL_SPEED, tween.top, tween.bottom)
+                    .with_easing(tween.easing)
+                    .with_duration(tween.duration)
+            }
             LetterboxMove::Hide => LetterboxMoveTrigger::hide(),
             LetterboxMove::Show => LetterboxMoveTrigger::show(),
             LetterboxMove::Close => LetterboxMoveTrigger::close(),
@@ -58,10 +113,3 @@ impl From<LetterboxMove> for LetterboxMoveTrigger {
         }
     }
 }
-
-impl From<LetterboxMoveTrigger> for LetterboxMove {
-    fn from(e: LetterboxMoveTrigger) -> Self {
-        let LetterboxMoveTrigger { target, speed } = e;
-        LetterboxMove::ToAt(target, speed)
-    }
-}
@@ -3,12 +3,16 @@ pub mod events;
 pub mod resources;
 mod systems;
 
-use crate::{
-    core::time::tick_time,
-    plugins::movement::linear::{components::TargetingPositionY, LinearMovementPlugin},
+use crate::core::{
+    event::on_trigger_write_event,
+    time::{tick_time, TimeMultiplier},
 };
 
-use self::{events::LetterboxMoveTrigger, resources::LetterboxTime, systems::*};
+use self::{
+    events::{LetterboxMoveFinishedTrigger, LetterboxMoveTrigger},
+    resources::LetterboxTime,
+    systems::*,
+};
 use bevy::prelude::*;
 
 pub struct LetterboxPlugin;
@@ -16,6 +20,7 @@ pub struct LetterboxPlugin;
 impl Plugin for LetterboxPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<LetterboxTime>()
+            .insert_resource(TimeMultiplier::<LetterboxTime>::new(1.))
             .init_state::<LetterboxPluginUpdateState>()
             .add_event::<LetterboxMoveTrigger>()
             .add_systems(
@@ -26,11 +31,14 @@ impl Plugin for LetterboxPlugin {
                 OnEnter(LetterboxPluginUpdateState::Inactive),
                 on_letterbox_shutdown,
             )
-            .add_plugins(LinearMovementPlugin::<LetterboxTime, TargetingPositionY>::default())
+            .add_event::<LetterboxMoveFinishedTrigger>()
+            .observe(on_trigger_write_event::<LetterboxMoveFinishedTrigger>)
             .observe(on_move)
             .add_systems(
                 Update,
-                (tick_time::<LetterboxTime>).run_if(in_state(LetterboxPluginUpdateState::Active)),
+                (tick_time::<LetterboxTime>, update_letterbox_movement)
+                    .chain()
+                    .run_if(in_state(LetterboxPluginUpdateState::Active)),
             );
     }
 }
@@ -1,13 +1,10 @@
 use super::components::*;
-use super::events::LetterboxMoveTrigger;
+use super::events::{LetterboxMoveFinishedTrigger, LetterboxMoveTrigger};
 use super::resources::LetterboxTime;
 use crate::components::GBColor;
 use crate::debug::plugin::debug_print_shutdown;
 use crate::debug::plugin::debug_print_startup;
 use crate::globals::mark_for_despawn_by_query;
-use crate::plugins::movement::linear::components::LinearMovementBundle;
-// use crate::plugins::movement::linear::components::LinearPositionRemovalBundle;
-use crate::plugins::movement::linear::components::TargetingPositionY;
 use crate::{
     cutscene::data::CutsceneLayer, globals::SCREEN_RESOLUTION, layer::Layer,
     pixel::components::PxRectangle,
@@ -72,30 +69,51 @@ pub fn on_move(
     bottom_query: Query<(Entity, &PxSubPosition), With<LetterboxBottom>>,
 ) {
     let e = trigger.event();
-    for xs in top_query.iter() {
-        let target = SCREEN_RESOLUTION.y as f32 - e.target;
-        insert_linear_movement(&mut commands, xs, target, e.speed);
+    if let Some(top) = e.top {
+        for xs in top_query.iter() {
+            let target = SCREEN_RESOLUTION.y as f32 - top;
+            insert_movement(&mut commands, xs, target, e);
+        }
     }
 
-    for xs in bottom_query.iter() {
-        insert_linear_movement(&mut commands, xs, e.target, e.speed);
+    if let Some(bottom) = e.bottom {
+        for xs in bottom_query.iter() {
+            insert_movement(&mut commands, xs, bottom, e);
+        }
     }
 }
 
-pub fn insert_linear_movement(
+pub fn insert_movement(
     commands: &mut Commands,
     (entity, position): (Entity, &PxSubPosition),
     target: f32,
-    speed: f32,
+    e: &LetterboxMoveTrigger,
 ) {
-    let speed = speed * (target - position.y).signum();
-    commands
-        .entity(entity)
-        // TODO review why this was removed
-        // .remove::<LinearPositionRemovalBundle<LetterboxTime, TargetingPositionY>>()
-        .insert(
-            LinearMovementBundle::<LetterboxTime, TargetingPositionY>::new(
-                position.y, target, speed,
-            ),
-        );
+    commands.entity(entity).insert(LetterboxMovement::new(
+        position.y, target, e.speed, e.easing, e.duration,
+    ));
+}
+
+pub fn update_letterbox_movement(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut LetterboxMovement, &mut PxSubPosition)>,
+    time: Res<LetterboxTime>,
+) {
+    let mut finished = false;
+    let mut moving = false;
+    for (entity, mut movement, mut position) in query.iter_mut() {
+        movement.elapsed += time.delta();
+        position.0.y = movement.position();
+
+        if movement.is_finished() {
+            commands.entity(entity).remove::<LetterboxMovement>();
+            finished = true;
+        } else {
+            moving = true;
+        }
+    }
+
+    if finished && !moving {
+        commands.trigger(LetterboxMoveFinishedTrigger);
+    }
 }
@@ -4,6 +4,7 @@ mod assets;
 pub mod bevy_utils;
 mod components;
 mod core;
+mod credits;
 pub mod cutscene;
 mod data;
 pub mod debug;
@@ -4,6 +4,7 @@ mod assets;
 mod bevy_utils;
 mod components;
 mod core;
+mod credits;
 mod cutscene;
 mod data;
 mod debug;
@@ -28,11 +29,12 @@ use bevy::prelude::*;
 use bevy_framepace::*;
 use bevy_utils::despawn_entities;
 use components::{DespawnMark, VolumeSettings};
+use credits::CreditsPlugin;
 use cutscene::CutscenePlugin;
 use debug::DebugPlugin;
 use game::GamePlugin;
-use globals::{DEFAULT_CROSSHAIR_INDEX, SCREEN_RESOLUTION, VIEWPORT_RESOLUTION};
-use input::GBInput;
+use globals::{SCREEN_RESOLUTION, VIEWPORT_RESOLUTION};
+use input::{GBInput, GBInputBindings};
 use layer::Layer;
 use leafwing_input_manager::prelude::InputManagerPlugin;
 use letterbox::LetterboxPlugin;
@@ -42,11 +44,15 @@ use resources::DifficultySelected;
 use seldom_pixel::prelude::*;
 use stage::{player::crosshair::CrosshairSettings, StagePlugin};
 use systems::{
-    camera::move_camera,
+    audio::apply_volume_settings,
+    camera::update_camera,
     movement::{update_position_x, update_position_y},
-    setup::{init_gb_input, set_framespace, spawn_camera},
+    setup::{
+        init_gb_input, load_gb_input_bindings, load_volume_settings, set_framespace, spawn_camera,
+    },
     *,
 };
+use transitions::TransitionPlugin;
 
 fn main() {
     let title: String = "CARCINISATION".to_string();
@@ -93,16 +99,22 @@ fn main() {
         .add_plugins(InputManagerPlugin::<GBInput>::default())
         .add_plugins(FramepacePlugin)
         .add_plugins(PixelPlugin::<Layer>::default())
-        .add_systems(Startup, (spawn_camera, set_framespace, init_gb_input))
+        .add_systems(PreStartup, (load_gb_input_bindings, load_volume_settings))
+        .add_systems(Startup, (spawn_camera, set_framespace))
+        .add_systems(
+            PreUpdate,
+            init_gb_input.run_if(resource_changed::<GBInputBindings>),
+        )
         // Graphics and Game
         .insert_resource(ClearColor(Color::BLACK))
-        .insert_resource(CrosshairSettings(DEFAULT_CROSSHAIR_INDEX))
+        .init_resource::<CrosshairSettings>()
         .add_plugins(PxPlugin::<Layer>::new(
             SCREEN_RESOLUTION,
             "palette/base.png".into(),
         ))
-        // .add_plugins(TransitionVenetianPlugin)
+        .add_plugins(TransitionPlugin)
         .add_plugins(CutscenePlugin)
+        .add_plugins(CreditsPlugin)
         .add_plugins(LetterboxPlugin)
         .add_plugins(MainMenuPlugin)
         .add_plugins(StagePlugin)
@@ -112,7 +124,8 @@ fn main() {
         .add_systems(
             Update,
             (
-                move_camera,
+                update_camera,
+                apply_volume_settings.run_if(resource_changed::<VolumeSettings>),
                 update_position_x,
                 update_position_y,
                 // transition_to_game_state,
@@ -17,3 +17,9 @@ pub struct PressStartScreenEntity;
 
 #[derive(Component)]
 pub struct DifficultySelectScreenEntity;
+
+#[derive(Component)]
+pub struct ControlsScreenEntity;
+
+#[derive(Component)]
+pub struct OptionsScreenEntity;
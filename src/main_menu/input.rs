@@ -1,8 +1,8 @@
-use crate::input::GBInput;
+use crate::input::{GBInput, GBInputBindings};
 use bevy::prelude::*;
 use bevy::reflect::Reflect;
+use leafwing_input_manager::action_state::ActionState;
 use leafwing_input_manager::Actionlike;
-use leafwing_input_manager::{action_state::ActionState, input_map::InputMap};
 
 #[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
 pub enum MainMenuScreenInput {
@@ -13,15 +13,15 @@ pub enum MainMenuScreenInput {
     Cancel,
 }
 
-pub fn init_input(mut commands: Commands) {
-    let ms: Vec<(MainMenuScreenInput, KeyCode)> = vec![
-        (MainMenuScreenInput::Select, GBInput::A.into()),
-        (MainMenuScreenInput::Cancel, GBInput::B.into()),
-        (MainMenuScreenInput::Down, GBInput::Down.into()),
-        (MainMenuScreenInput::Up, GBInput::Up.into()),
-        (MainMenuScreenInput::Switch, GBInput::Select.into()),
-        (MainMenuScreenInput::Select, GBInput::Start.into()),
+pub fn init_input(mut commands: Commands, bindings: Res<GBInputBindings>) {
+    let ms: Vec<(MainMenuScreenInput, GBInput)> = vec![
+        (MainMenuScreenInput::Select, GBInput::A),
+        (MainMenuScreenInput::Cancel, GBInput::B),
+        (MainMenuScreenInput::Down, GBInput::Down),
+        (MainMenuScreenInput::Up, GBInput::Up),
+        (MainMenuScreenInput::Switch, GBInput::Select),
+        (MainMenuScreenInput::Select, GBInput::Start),
     ];
     commands.insert_resource(ActionState::<MainMenuScreenInput>::default());
-    commands.insert_resource(InputMap::<MainMenuScreenInput>::new(ms));
+    commands.insert_resource(bindings.make_input_map(ms));
 }
@@ -6,7 +6,7 @@ mod systems;
 
 use self::{
     events::{ChangeMainMenuScreenTrigger, MainMenuShutdownEvent, MainMenuStartupEvent},
-    resources::DifficultySelection,
+    resources::{ControlsSelection, DifficultySelection, NextMainMenuScreen, OptionsSelection},
     systems::{
         interactions::*,
         layout::*,
@@ -21,7 +21,10 @@ impl Plugin for MainMenuPlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<MainMenuPluginUpdateState>()
             .init_resource::<MainMenuScreen>()
+            .init_resource::<NextMainMenuScreen>()
             .init_resource::<DifficultySelection>()
+            .init_resource::<ControlsSelection>()
+            .init_resource::<OptionsSelection>()
             .add_event::<ChangeMainMenuScreenTrigger>()
             .observe(on_change_main_menu_screen)
             .add_event::<MainMenuStartupEvent>()
@@ -35,7 +38,12 @@ impl Plugin for MainMenuPlugin {
             // )
             .add_systems(
                 Update,
-                (spawn_game_difficulty_screen, spawn_press_start_screen)
+                (
+                    spawn_game_difficulty_screen,
+                    spawn_press_start_screen,
+                    spawn_options_screen,
+                    spawn_controls_screen,
+                )
                     .run_if(in_state(MainMenuPluginUpdateState::Active)),
             )
             .add_systems(
@@ -47,9 +55,16 @@ impl Plugin for MainMenuPlugin {
                         .run_if(resource_exists_and_equals(MainMenuScreen::MainMenuSelect)),
                     (game_difficulty_select_change, game_difficulty_select_option)
                         .run_if(resource_exists_and_equals(MainMenuScreen::DifficultySelect)),
+                    (controls_select_change, controls_select_option)
+                        .chain()
+                        .run_if(resource_exists_and_equals(MainMenuScreen::ControlsSelect)),
+                    (options_select_change, options_select_option)
+                        .chain()
+                        .run_if(resource_exists_and_equals(MainMenuScreen::OptionsSelect)),
                 )
                     .run_if(in_state(MainMenuPluginUpdateState::Active)),
-            );
+            )
+            .add_systems(Last, apply_next_main_menu_screen);
     }
 }
 
@@ -67,4 +82,6 @@ pub enum MainMenuScreen {
     MainMenuSelect,
     // TODO can this be nested under MainSelect?
     DifficultySelect,
+    OptionsSelect,
+    ControlsSelect,
 }
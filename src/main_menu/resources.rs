@@ -1,5 +1,44 @@
-use crate::game::resources::Difficulty;
+use super::MainMenuScreen;
+use crate::{game::resources::Difficulty, input::GBInput};
 use bevy::prelude::*;
+use strum_macros::EnumIter;
+
+/**
+ * Screen requested during this frame, applied in `Last` so that the press that
+ * switched screens isn't read again by the next screen's handlers
+ */
+#[derive(Resource, Debug, Clone, Eq, PartialEq, Default)]
+pub struct NextMainMenuScreen(pub Option<MainMenuScreen>);
 
 #[derive(Resource, Debug, Clone, Eq, PartialEq, Default)]
 pub struct DifficultySelection(pub Difficulty);
+
+#[derive(Resource, Debug, Clone, Eq, PartialEq)]
+pub struct ControlsSelection {
+    pub action: GBInput,
+    /**
+     * Waiting for the next key or gamepad button to bind to the selected action
+     */
+    pub rebinding: bool,
+}
+
+impl Default for ControlsSelection {
+    fn default() -> Self {
+        Self {
+            action: GBInput::A,
+            rebinding: false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, EnumIter, Eq, PartialEq)]
+pub enum OptionsEntry {
+    #[default]
+    Crosshair,
+    Pointer,
+    AimAssist,
+    Controls,
+}
+
+#[derive(Resource, Debug, Clone, Eq, PartialEq, Default)]
+pub struct OptionsSelection(pub OptionsEntry);
@@ -1,11 +1,19 @@
 use crate::{
     game::{events::GameStartupTrigger, resources::Difficulty},
-    input::GBInput,
-    main_menu::{events::MainMenuShutdownEvent, resources::DifficultySelection},
+    globals::mark_for_despawn_by_query,
+    input::{GBInput, GBInputBindings},
+    main_menu::{
+        components::{ControlsScreenEntity, OptionsScreenEntity},
+        events::{ChangeMainMenuScreenTrigger, MainMenuShutdownEvent},
+        resources::{ControlsSelection, DifficultySelection, OptionsEntry, OptionsSelection},
+        MainMenuScreen,
+    },
     resources::DifficultySelected,
+    stage::player::{crosshair::CrosshairSettings, resources::AimSettings},
 };
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::ActionState;
+use strum::IntoEnumIterator;
 
 pub fn check_press_start_input(mut commands: Commands, gb_input: Res<ActionState<GBInput>>) {
     if gb_input.just_pressed(&GBInput::Start)
@@ -14,6 +22,8 @@ pub fn check_press_start_input(mut commands: Commands, gb_input: Res<ActionState
     {
         commands.trigger(GameStartupTrigger);
         commands.trigger(MainMenuShutdownEvent);
+    } else if gb_input.just_pressed(&GBInput::Select) {
+        commands.trigger(ChangeMainMenuScreenTrigger(MainMenuScreen::OptionsSelect));
     }
 }
 
@@ -57,3 +67,131 @@ pub fn game_difficulty_select_option(
         selected_state.0 = selection_state.0;
     }
 }
+
+pub fn controls_select_change(
+    mut selection: ResMut<ControlsSelection>,
+    gb_input: Res<ActionState<GBInput>>,
+) {
+    if selection.rebinding {
+        return;
+    }
+
+    let input =
+        gb_input.just_pressed(&GBInput::Down) as i8 - gb_input.just_pressed(&GBInput::Up) as i8;
+    if input != 0 {
+        let actions = GBInput::iter().collect::<Vec<_>>();
+        let index = actions
+            .iter()
+            .position(|x| *x == selection.action)
+            .unwrap_or(0) as i8;
+        if let Some(action) = usize::try_from(index + input)
+            .ok()
+            .and_then(|i| actions.get(i))
+        {
+            selection.action = *action;
+        } else {
+            // Little sound indicating bound
+        }
+    }
+}
+
+/**
+ * A starts listening for the next key or gamepad button, B saves and goes back to the options
+ */
+pub fn controls_select_option(
+    mut commands: Commands,
+    mut selection: ResMut<ControlsSelection>,
+    mut bindings: ResMut<GBInputBindings>,
+    gb_input: Res<ActionState<GBInput>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    query: Query<Entity, With<ControlsScreenEntity>>,
+) {
+    if selection.rebinding {
+        let action = selection.action;
+        if let Some(key) = keys.get_just_pressed().next() {
+            bindings.bind_key(action, *key);
+        } else if let Some(button) = gamepad_buttons.get_just_pressed().next() {
+            bindings.bind_button(action, button.button_type);
+        } else {
+            return;
+        }
+        selection.rebinding = false;
+        bindings.save();
+    } else if gb_input.just_pressed(&GBInput::A) {
+        selection.rebinding = true;
+    } else if gb_input.just_pressed(&GBInput::B) {
+        bindings.save();
+        mark_for_despawn_by_query(&mut commands, &query);
+        commands.trigger(ChangeMainMenuScreenTrigger(MainMenuScreen::OptionsSelect));
+    }
+}
+
+pub fn options_select_change(
+    mut selection: ResMut<OptionsSelection>,
+    gb_input: Res<ActionState<GBInput>>,
+) {
+    let input =
+        gb_input.just_pressed(&GBInput::Down) as i8 - gb_input.just_pressed(&GBInput::Up) as i8;
+    if input != 0 {
+        let entries = OptionsEntry::iter().collect::<Vec<_>>();
+        let index = entries.iter().position(|x| *x == selection.0).unwrap_or(0) as i8;
+        if let Some(entry) = usize::try_from(index + input)
+            .ok()
+            .and_then(|i| entries.get(i))
+        {
+            selection.0 = *entry;
+        } else {
+            // Little sound indicating bound
+        }
+    }
+}
+
+/**
+ * Left and Right cycle the selected value, A toggles it or enters the controls screen,
+ * B saves the settings and goes back
+ */
+pub fn options_select_option(
+    mut commands: Commands,
+    selection: Res<OptionsSelection>,
+    mut crosshair_settings: ResMut<CrosshairSettings>,
+    mut aim_settings: ResMut<AimSettings>,
+    gb_input: Res<ActionState<GBInput>>,
+    query: Query<Entity, With<OptionsScreenEntity>>,
+) {
+    let input =
+        gb_input.just_pressed(&GBInput::Right) as i8 - gb_input.just_pressed(&GBInput::Left) as i8;
+    let toggle = input != 0 || gb_input.just_pressed(&GBInput::A);
+
+    match selection.0 {
+        OptionsEntry::Crosshair => {
+            if input > 0 || gb_input.just_pressed(&GBInput::A) {
+                *crosshair_settings = crosshair_settings.next();
+            } else if input < 0 {
+                *crosshair_settings = crosshair_settings.previous();
+            }
+        }
+        OptionsEntry::Pointer => {
+            if toggle {
+                aim_settings.pointer = !aim_settings.pointer;
+            }
+        }
+        OptionsEntry::AimAssist => {
+            if toggle {
+                aim_settings.aim_assist = !aim_settings.aim_assist;
+            }
+        }
+        OptionsEntry::Controls => {
+            if gb_input.just_pressed(&GBInput::A) {
+                commands.trigger(ChangeMainMenuScreenTrigger(MainMenuScreen::ControlsSelect));
+            }
+        }
+    }
+
+    if gb_input.just_pressed(&GBInput::B) {
+        crosshair_settings.save();
+        aim_settings.save();
+        mark_for_despawn_by_query(&mut commands, &query);
+        commands.trigger(ChangeMainMenuScreenTrigger(MainMenuScreen::PressStart));
+    }
+}
@@ -6,9 +6,15 @@ use crate::{
         mark_for_despawn_by_query, SCREEN_RESOLUTION, SCREEN_RESOLUTION_F32, TYPEFACE_CHARACTERS,
         TYPEFACE_INVERTED_PATH,
     },
+    input::{GBInput, GBInputBindings},
     layer::Layer,
-    main_menu::{events::ChangeMainMenuScreenTrigger, MainMenuScreen},
+    main_menu::{
+        events::ChangeMainMenuScreenTrigger,
+        resources::{ControlsSelection, NextMainMenuScreen, OptionsEntry, OptionsSelection},
+        MainMenuScreen,
+    },
     pixel::components::PxRectangle,
+    stage::player::{crosshair::CrosshairSettings, resources::AimSettings, CrosshairInfo},
 };
 use assert_assets_path::assert_assets_path;
 use bevy::prelude::*;
@@ -24,7 +30,9 @@ pub fn on_change_main_menu_screen(
     difficulty_select_query: Query<Entity, With<DifficultySelectScreenEntity>>,
     press_start_query: Query<Entity, With<PressStartScreenEntity>>,
     main_menu_select_query: Query<Entity, With<MainMenuSelectScreenEntity>>,
-    mut screen: ResMut<MainMenuScreen>,
+    options_query: Query<Entity, With<OptionsScreenEntity>>,
+    controls_query: Query<Entity, With<ControlsScreenEntity>>,
+    mut next_screen: ResMut<NextMainMenuScreen>,
 ) {
     let e = trigger.event();
     match e.0 {
@@ -34,9 +42,29 @@ pub fn on_change_main_menu_screen(
         MainMenuScreen::MainMenuSelect => {
             mark_for_despawn_by_query(&mut commands, &main_menu_select_query)
         }
-        MainMenuScreen::PressStart => mark_for_despawn_by_query(&mut commands, &press_start_query),
+        MainMenuScreen::PressStart => {
+            mark_for_despawn_by_query(&mut commands, &press_start_query);
+            mark_for_despawn_by_query(&mut commands, &options_query);
+        }
+        MainMenuScreen::OptionsSelect => {
+            mark_for_despawn_by_query(&mut commands, &press_start_query);
+            mark_for_despawn_by_query(&mut commands, &controls_query);
+        }
+        MainMenuScreen::ControlsSelect => {
+            mark_for_despawn_by_query(&mut commands, &options_query);
+            mark_for_despawn_by_query(&mut commands, &controls_query);
+        }
+    }
+    next_screen.0 = Some(e.0.clone());
+}
+
+pub fn apply_next_main_menu_screen(
+    mut next_screen: ResMut<NextMainMenuScreen>,
+    mut screen: ResMut<MainMenuScreen>,
+) {
+    if let Some(next) = next_screen.0.take() {
+        *screen = next;
     }
-    *screen = e.0.clone();
 }
 
 pub fn spawn_main_menu(mut commands: Commands, mut assets_sprite: PxAssets<PxSprite>) {
@@ -140,3 +168,251 @@ pub fn spawn_game_difficulty_screen(
         }
     }
 }
+
+const OPTIONS_ROW_HEIGHT: i32 = 16;
+const OPTIONS_ROWS_TOP: i32 = 104;
+const OPTIONS_LABEL_X: i32 = 12;
+const OPTIONS_VALUE_X: i32 = 84;
+
+/**
+ * Respawned whenever the selection or any of the settings change
+ */
+pub fn spawn_options_screen(
+    mut commands: Commands,
+    mut assets_sprite: PxAssets<PxSprite>,
+    mut typefaces: PxAssets<PxTypeface>,
+    screen: Res<MainMenuScreen>,
+    selection: Res<OptionsSelection>,
+    crosshair_settings: Res<CrosshairSettings>,
+    aim_settings: Res<AimSettings>,
+    query: Query<Entity, With<OptionsScreenEntity>>,
+) {
+    if *screen.as_ref() != MainMenuScreen::OptionsSelect
+        || !(screen.is_changed()
+            || selection.is_changed()
+            || crosshair_settings.is_changed()
+            || aim_settings.is_changed())
+    {
+        return;
+    }
+
+    mark_for_despawn_by_query(&mut commands, &query);
+
+    let typeface = typefaces.load(TYPEFACE_INVERTED_PATH, TYPEFACE_CHARACTERS, [(' ', 4)]);
+    let width = SCREEN_RESOLUTION.x as i32;
+
+    commands.spawn((
+        MainMenuEntity,
+        OptionsScreenEntity,
+        PxSubPosition(Vec2::new(
+            SCREEN_RESOLUTION_F32.x / 2.,
+            SCREEN_RESOLUTION_F32.y / 2.,
+        )),
+        PxRectangle {
+            anchor: PxAnchor::Center,
+            canvas: PxCanvas::Camera,
+            color: GBColor::White,
+            width: SCREEN_RESOLUTION.x - 8,
+            height: SCREEN_RESOLUTION.y - 8,
+            layer: Layer::UIBackground,
+        },
+    ));
+
+    let crosshair_info = CrosshairInfo::crosshair_sprite(&mut assets_sprite, &crosshair_settings);
+    let show_toggle = |x: bool| if x { "On" } else { "Off" }.to_string();
+
+    let mut spawn_text = |rect: IRect, text: String, alignment: PxAnchor| {
+        commands.spawn((
+            MainMenuEntity,
+            OptionsScreenEntity,
+            PxTextBundle::<Layer> {
+                alignment,
+                canvas: PxCanvas::Camera,
+                layer: Layer::UI,
+                rect: rect.into(),
+                text: text.clone().into(),
+                typeface: typeface.clone(),
+                ..default()
+            },
+            Name::new(format!("Text<{}>", text)),
+        ));
+    };
+
+    spawn_text(
+        IRect::new(0, 126, width, 136),
+        "Options".to_string(),
+        PxAnchor::Center,
+    );
+
+    for (i, entry) in OptionsEntry::iter().enumerate() {
+        let y = OPTIONS_ROWS_TOP - i as i32 * OPTIONS_ROW_HEIGHT;
+        let (label, value) = match entry {
+            OptionsEntry::Crosshair => ("Crosshair", "<   >".to_string()),
+            OptionsEntry::Pointer => ("Pointer", show_toggle(aim_settings.pointer)),
+            OptionsEntry::AimAssist => ("Aim assist", show_toggle(aim_settings.aim_assist)),
+            OptionsEntry::Controls => ("Controls", String::new()),
+        };
+        let selected = entry == selection.0;
+
+        spawn_text(
+            IRect::new(OPTIONS_LABEL_X, y, OPTIONS_VALUE_X, y + OPTIONS_ROW_HEIGHT),
+            format!("{}{}", if selected { ">" } else { " " }, label),
+            PxAnchor::CenterLeft,
+        );
+        if !value.is_empty() {
+            spawn_text(
+                IRect::new(OPTIONS_VALUE_X, y, width - 8, y + OPTIONS_ROW_HEIGHT),
+                value,
+                PxAnchor::Center,
+            );
+        }
+    }
+
+    spawn_text(
+        IRect::new(0, 8, width, 18),
+        "A Change  B Back".to_string(),
+        PxAnchor::Center,
+    );
+
+    // Live preview, kept in sync by the same system that updates the player's crosshair
+    commands.spawn((
+        MainMenuEntity,
+        OptionsScreenEntity,
+        crosshair_info.crosshair,
+        PxSpriteBundle::<Layer> {
+            sprite: crosshair_info.sprite,
+            anchor: PxAnchor::Center,
+            canvas: PxCanvas::Camera,
+            layer: Layer::UI,
+            ..default()
+        },
+        PxSubPosition(Vec2::new(
+            ((OPTIONS_VALUE_X + width - 8) / 2) as f32,
+            (OPTIONS_ROWS_TOP + OPTIONS_ROW_HEIGHT / 2) as f32,
+        )),
+        Name::new("CrosshairPreview"),
+    ));
+}
+
+const CONTROLS_ROW_HEIGHT: i32 = 12;
+const CONTROLS_ROWS_TOP: i32 = 116;
+const CONTROLS_LABEL_X: i32 = 8;
+const CONTROLS_KEY_X: i32 = 52;
+const CONTROLS_BUTTON_X: i32 = 108;
+
+/**
+ * Respawned whenever the selection or the bindings change
+ */
+pub fn spawn_controls_screen(
+    mut commands: Commands,
+    mut typefaces: PxAssets<PxTypeface>,
+    screen: Res<MainMenuScreen>,
+    selection: Res<ControlsSelection>,
+    bindings: Res<GBInputBindings>,
+    query: Query<Entity, With<ControlsScreenEntity>>,
+) {
+    if *screen.as_ref() != MainMenuScreen::ControlsSelect
+        || !(screen.is_changed() || selection.is_changed() || bindings.is_changed())
+    {
+        return;
+    }
+
+    mark_for_despawn_by_query(&mut commands, &query);
+
+    let typeface = typefaces.load(TYPEFACE_INVERTED_PATH, TYPEFACE_CHARACTERS, [(' ', 4)]);
+    let width = SCREEN_RESOLUTION.x as i32;
+
+    commands.spawn((
+        MainMenuEntity,
+        ControlsScreenEntity,
+        PxSubPosition(Vec2::new(
+            SCREEN_RESOLUTION_F32.x / 2.,
+            SCREEN_RESOLUTION_F32.y / 2.,
+        )),
+        PxRectangle {
+            anchor: PxAnchor::Center,
+            canvas: PxCanvas::Camera,
+            color: GBColor::White,
+            width: SCREEN_RESOLUTION.x - 8,
+            height: SCREEN_RESOLUTION.y - 8,
+            layer: Layer::UIBackground,
+        },
+    ));
+
+    let mut spawn_text = |rect: IRect, text: String, alignment: PxAnchor| {
+        commands.spawn((
+            MainMenuEntity,
+            ControlsScreenEntity,
+            PxTextBundle::<Layer> {
+                alignment,
+                canvas: PxCanvas::Camera,
+                layer: Layer::UI,
+                rect: rect.into(),
+                text: text.clone().into(),
+                typeface: typeface.clone(),
+                ..default()
+            },
+            Name::new(format!("Text<{}>", text)),
+        ));
+    };
+
+    spawn_text(
+        IRect::new(0, 126, width, 136),
+        "Controls".to_string(),
+        PxAnchor::Center,
+    );
+
+    for (i, action) in GBInput::iter().enumerate() {
+        let y = CONTROLS_ROWS_TOP - i as i32 * CONTROLS_ROW_HEIGHT;
+        let selected = action == selection.action;
+        let (key, button) = if selected && selection.rebinding {
+            ("...".to_string(), "...".to_string())
+        } else {
+            (
+                bindings.key(action).map_or("-".to_string(), show_key),
+                bindings
+                    .button(action)
+                    .map_or("-".to_string(), |x| format!("{:?}", x)),
+            )
+        };
+
+        spawn_text(
+            IRect::new(CONTROLS_LABEL_X, y, CONTROLS_KEY_X, y + CONTROLS_ROW_HEIGHT),
+            format!("{}{:?}", if selected { ">" } else { " " }, action),
+            PxAnchor::CenterLeft,
+        );
+        spawn_text(
+            IRect::new(
+                CONTROLS_KEY_X,
+                y,
+                CONTROLS_BUTTON_X,
+                y + CONTROLS_ROW_HEIGHT,
+            ),
+            key,
+            PxAnchor::CenterLeft,
+        );
+        spawn_text(
+            IRect::new(CONTROLS_BUTTON_X, y, width, y + CONTROLS_ROW_HEIGHT),
+            button,
+            PxAnchor::CenterLeft,
+        );
+    }
+
+    spawn_text(
+        IRect::new(0, 8, width, 18),
+        "A Rebind  B Back".to_string(),
+        PxAnchor::Center,
+    );
+}
+
+/**
+ * Drops the redundant prefixes from the key names so they fit in the screen
+ */
+fn show_key(key: KeyCode) -> String {
+    let name = format!("{:?}", key);
+    name.strip_prefix("Key")
+        .or_else(|| name.strip_prefix("Arrow"))
+        .or_else(|| name.strip_prefix("Digit"))
+        .unwrap_or(&name)
+        .to_string()
+}
@@ -4,7 +4,8 @@ use crate::{
     main_menu::{
         components::{MainMenu, MainMenuEntity},
         events::{MainMenuShutdownEvent, MainMenuStartupEvent},
-        MainMenuPluginUpdateState,
+        resources::NextMainMenuScreen,
+        MainMenuPluginUpdateState, MainMenuScreen,
     },
 };
 use bevy::prelude::*;
@@ -12,14 +13,21 @@ use bevy::prelude::*;
 #[cfg(debug_assertions)]
 const DEBUG_MODULE: &str = "MainMenu";
 
+/**
+ * Also reached on the way back from a game, so it starts over from the press start screen
+ */
 pub fn on_main_menu_startup(
     _trigger: Trigger<MainMenuStartupEvent>,
     mut next_state: ResMut<NextState<MainMenuPluginUpdateState>>,
+    mut screen: ResMut<MainMenuScreen>,
+    mut next_screen: ResMut<NextMainMenuScreen>,
 ) {
     #[cfg(debug_assertions)]
     debug_print_startup(DEBUG_MODULE);
 
     next_state.set(MainMenuPluginUpdateState::Active);
+    *screen = MainMenuScreen::PressStart;
+    next_screen.0 = None;
 }
 
 pub fn on_main_menu_shutdown(
@@ -2,7 +2,7 @@ pub mod components;
 mod systems;
 
 use self::systems::*;
-use super::structs::Magnitude;
+use super::{structs::Magnitude, MovementSystems};
 use crate::core::time::DeltaTime;
 use bevy::prelude::*;
 use std::marker::PhantomData;
@@ -30,11 +30,15 @@ impl<T: DeltaTime + 'static + Resource, P: Magnitude + Component> Plugin
     for LinearMovementPlugin<T, P>
 {
     fn build(&self, app: &mut App) {
-        app.add_systems(PreUpdate, on_position_added::<T, P>)
-            .add_systems(
-                Update,
-                ((on_reached::<T, P>, update::<T, P>, check_reached::<T, P>).chain(),),
-            );
+        app.add_systems(
+            PreUpdate,
+            on_position_added::<T, P>.in_set(MovementSystems::<T>::default()),
+        )
+        .add_systems(
+            Update,
+            ((on_reached::<T, P>, update::<T, P>, check_reached::<T, P>).chain(),)
+                .in_set(MovementSystems::<T>::default()),
+        );
     }
 }
 
@@ -69,7 +73,8 @@ impl<T: DeltaTime + 'static + Resource, X: Magnitude + Component, Y: Magnitude +
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            (check_2d_x_reached::<T, X, Y>, check_2d_y_reached::<T, X, Y>),
+            (check_2d_x_reached::<T, X, Y>, check_2d_y_reached::<T, X, Y>)
+                .in_set(MovementSystems::<T>::default()),
         );
     }
 }
@@ -1,3 +1,47 @@
 pub mod linear;
 pub mod pursue;
 pub mod structs;
+pub mod track;
+
+use bevy::prelude::*;
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+/**
+ * Every movement system driven by the clock T, so that a feature can hold them all still
+ */
+#[derive(SystemSet)]
+pub struct MovementSystems<T: Send + Sync + 'static>(PhantomData<T>);
+
+impl<T: Send + Sync + 'static> Default for MovementSystems<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Send + Sync + 'static> Clone for MovementSystems<T> {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl<T: Send + Sync + 'static> fmt::Debug for MovementSystems<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MovementSystems<{}>", std::any::type_name::<T>())
+    }
+}
+
+impl<T: Send + Sync + 'static> PartialEq for MovementSystems<T> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<T: Send + Sync + 'static> Eq for MovementSystems<T> {}
+
+impl<T: Send + Sync + 'static> Hash for MovementSystems<T> {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
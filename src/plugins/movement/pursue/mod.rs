@@ -2,7 +2,7 @@ pub mod components;
 mod systems;
 
 use self::systems::*;
-use super::structs::MovementVec2Position;
+use super::{structs::MovementVec2Position, MovementSystems};
 use crate::core::time::DeltaTime;
 use bevy::prelude::*;
 use std::marker::PhantomData;
@@ -38,7 +38,8 @@ impl<T: DeltaTime + 'static + Resource, P: MovementVec2Position + Component> Plu
                 check_y_reached::<T, P>,
                 check_reached::<T, P>,
                 on_position_added::<T, P>,
-            ),
+            )
+                .in_set(MovementSystems::<T>::default()),
         );
     }
 }
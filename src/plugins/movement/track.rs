@@ -0,0 +1,239 @@
+use crate::core::time::DeltaTime;
+use bevy::prelude::*;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+use std::{marker::PhantomData, time::Duration};
+
+/**
+ * Segments sampled in between each pair of waypoints
+ */
+pub const PATH_SAMPLES: usize = 16;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Reflect, Serialize)]
+pub enum Easing {
+    #[default]
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+}
+
+impl Easing {
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => 1. - (1. - t) * (1. - t),
+            Easing::EaseInOut => t * t * (3. - 2. * t),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Reflect, Serialize)]
+pub enum PathKind {
+    /**
+     * Passes through every waypoint
+     */
+    #[default]
+    CatmullRom,
+    /**
+     * Waypoints act as control points, only the ends are reached
+     */
+    Bezier,
+}
+
+/**
+ * Curve followed between a start and an end position, through the waypoints
+ */
+#[derive(Clone, Debug, Default, Deserialize, Reflect, Serialize)]
+pub struct Path {
+    #[serde(default)]
+    pub kind: PathKind,
+    pub waypoints: Vec<Vec2>,
+}
+
+impl Path {
+    pub fn sample(&self, start: Vec2, end: Vec2) -> Vec<Vec2> {
+        let mut points = vec![start];
+        points.extend(self.waypoints.iter().copied());
+        points.push(end);
+
+        match self.kind {
+            PathKind::CatmullRom => sample_catmull_rom(&points),
+            PathKind::Bezier => sample_bezier(&points),
+        }
+    }
+}
+
+fn sample_catmull_rom(points: &[Vec2]) -> Vec<Vec2> {
+    let last = points.len() - 1;
+    let mut sampled = vec![points[0]];
+    for i in 0..last {
+        let p0 = points[i.saturating_sub(1)];
+        let p1 = points[i];
+        let p2 = points[i + 1];
+        let p3 = points[(i + 2).min(last)];
+        for j in 1..=PATH_SAMPLES {
+            let t = j as f32 / PATH_SAMPLES as f32;
+            let t2 = t * t;
+            let t3 = t2 * t;
+            sampled.push(
+                0.5 * (2. * p1
+                    + (p2 - p0) * t
+                    + (2. * p0 - 5. * p1 + 4. * p2 - p3) * t2
+                    + (3. * p1 - p0 - 3. * p2 + p3) * t3),
+            );
+        }
+    }
+    sampled
+}
+
+fn sample_bezier(points: &[Vec2]) -> Vec<Vec2> {
+    let samples = PATH_SAMPLES * (points.len() - 1);
+    (0..=samples)
+        .map(|j| {
+            let t = j as f32 / samples as f32;
+            let mut current = points.to_vec();
+            while current.len() > 1 {
+                current = current.windows(2).map(|w| w[0].lerp(w[1], t)).collect();
+            }
+            current[0]
+        })
+        .collect()
+}
+
+/**
+ * Whether a movement starting at `speed` covers `distance` before a negative
+ * `acceleration` brings it to a stop
+ */
+pub fn acceleration_reaches(speed: f32, acceleration: f32, distance: f32) -> bool {
+    acceleration >= 0. || speed * speed + 2. * acceleration * distance > 0.
+}
+
+/**
+ * A sampled movement, shared by the stage camera, cutscene sprites and the editor preview
+ */
+#[derive(Clone, Debug, Reflect)]
+pub struct Track {
+    pub points: Vec<Vec2>,
+    /**
+     * Accumulated distance up to each point
+     */
+    pub distances: Vec<f32>,
+    pub speed: f32,
+    pub acceleration: Option<f32>,
+    pub easing: Easing,
+    pub duration: Duration,
+}
+
+impl Track {
+    /**
+     * Without a fixed duration, it is derived from the speed, and the acceleration if any
+     */
+    pub fn new(
+        points: Vec<Vec2>,
+        speed: f32,
+        acceleration: Option<f32>,
+        easing: Easing,
+        duration: Option<Duration>,
+    ) -> Self {
+        let mut distances = Vec::with_capacity(points.len());
+        let mut total = 0.;
+        for (i, point) in points.iter().enumerate() {
+            if i > 0 {
+                total += point.distance(points[i - 1]);
+            }
+            distances.push(total);
+        }
+
+        let acceleration = duration
+            .is_none()
+            .then_some(acceleration)
+            .flatten()
+            .filter(|a| *a != 0.)
+            .filter(|a| {
+                let reaches = acceleration_reaches(speed, *a, total);
+                if !reaches {
+                    warn!(
+                        "Acceleration {} stops the movement before the end of its track, ignoring it",
+                        a
+                    );
+                }
+                reaches
+            });
+        let duration = duration.unwrap_or_else(|| {
+            let secs = match acceleration {
+                Some(a) => ((speed * speed + 2. * a * total).sqrt() - speed) / a,
+                None if speed > 0. => total / speed,
+                None => 0.,
+            };
+            Duration::from_secs_f32(secs.max(0.))
+        });
+
+        Self {
+            points,
+            distances,
+            speed,
+            acceleration,
+            easing,
+            duration,
+        }
+    }
+
+    pub fn length(&self) -> f32 {
+        self.distances.last().copied().unwrap_or(0.)
+    }
+
+    pub fn end(&self) -> Vec2 {
+        self.points.last().copied().unwrap_or(Vec2::ZERO)
+    }
+
+    pub fn position_at(&self, elapsed: Duration) -> Vec2 {
+        if elapsed >= self.duration {
+            return self.end();
+        }
+        let secs = elapsed.as_secs_f32();
+        let distance = match self.acceleration {
+            Some(a) => self.speed * secs + 0.5 * a * secs * secs,
+            None => self.easing.apply(secs / self.duration.as_secs_f32()) * self.length(),
+        };
+        self.position_at_distance(distance)
+    }
+
+    pub fn position_at_distance(&self, distance: f32) -> Vec2 {
+        let index = self.distances.partition_point(|d| *d < distance);
+        if index == 0 {
+            return self.points.first().copied().unwrap_or(Vec2::ZERO);
+        }
+        if index >= self.points.len() {
+            return self.end();
+        }
+        let from = self.distances[index - 1];
+        let span = self.distances[index] - from;
+        let t = if span > 0. {
+            (distance - from) / span
+        } else {
+            1.
+        };
+        self.points[index - 1].lerp(self.points[index], t)
+    }
+}
+
+/**
+ * Moves an entity along a Track, through its TargetingPositionX and Y, following the clock T
+ */
+#[derive(new, Clone, Component, Debug)]
+pub struct TweenPath<T: DeltaTime> {
+    pub track: Track,
+    #[new(default)]
+    pub elapsed: Duration,
+    #[new(default)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: DeltaTime> TweenPath<T> {
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.track.duration
+    }
+}
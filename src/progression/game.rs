@@ -22,5 +22,6 @@ pub fn make_steps() -> Vec<GameStep> {
         // StageAssetGameStep(assert_assets_path!("stages/park.sg.ron").to_string()).into(),
         // StageAssetGameStep(assert_assets_path!("stages/spaceship.sg.ron").to_string()).into(),
         // StageAssetGameStep(assert_assets_path!("stages/asteroid.sg.ron").to_string()).into(),
+        CreditsGameStep::new(assert_assets_path!("credits/data.credits.ron").to_string()).into(),
     ]
 }
@@ -3,7 +3,9 @@ use std::sync::Arc;
 use crate::stage::components::placement::Depth;
 use crate::stage::components::{MovementStageStep, StopStageStep};
 use crate::stage::data::*;
-use crate::stage::destructible::data::{DestructibleSpawn, LampDepth, TrashcanDepth};
+use crate::stage::destructible::data::{
+    DestructibleSpawn, LampDepth, PlantDepth, TrashcanDepth, WindowDepth,
+};
 use crate::stage::enemy::data::steps::EnemyStep;
 use crate::stage::enemy::entity::EnemyType;
 use assert_assets_path::assert_assets_path;
@@ -22,9 +24,14 @@ lazy_static! {
             path: assert_assets_path!("backgrounds/rugpark/skybox.png").to_string(),
             frames: 2,
         },
+        layers: vec![],
         start_coordinates: Some(Vec2::new(0.0, 0.0)),
+        loot_seed: None,
         spawns: make_spawns(),
         steps: make_steps(),
+        clear_transition: None,
+        death_transition: None,
+        clear_flags: vec![],
     }
     .into();
 }
@@ -34,6 +41,10 @@ pub fn make_spawns() -> Vec<StageSpawn> {
         ObjectSpawn::rugpark_sign_base(400., 20.).into(),
         DestructibleSpawn::trashcan_base(100., 67., TrashcanDepth::Six).into(),
         DestructibleSpawn::trashcan_base(220., 67., TrashcanDepth::Six).into(),
+        DestructibleSpawn::window_base(140., 60., WindowDepth::Five).into(),
+        DestructibleSpawn::plant_base(120., 40., PlantDepth::Four)
+            .with_explosion(30., 100)
+            .into(),
         // DestructibleSpawn::crystal_base(125., 32.).into(),
         // DestructibleSpawn::mushroom_base(60., 12.).into(),
         ObjectSpawn::fibertree_base(30., OBJECT_FIBERTREE_Y).into(),
@@ -136,6 +147,11 @@ pub fn make_steps() -> Vec<StageStep> {
                     .with_elapsed_f32(45.1)
                     .drops(PickupDropSpawn::new(PickupType::SmallHealthpack).into())
                     .into(),
+                EnemySpawn::mosquito_variant_linear()
+                    .with_y(90.)
+                    .with_elapsed_f32(60.0)
+                    .drops(PickupDropSpawn::new(PickupType::Bomb).into())
+                    .into(),
                 EnemySpawn::mosquito_variant_approacher()
                     .with_coordinates(Vec2::new(140.0, 130.0))
                     .with_elapsed_f32(2.1)
@@ -10,9 +10,11 @@ use crate::{
             placement::Depth,
         },
         events::DamageEvent,
+        pickup::components::PickupCollection,
         player::components::{
             PlayerAttack, UnhittableList, Weapon, ATTACK_GUN_DAMAGE, ATTACK_PINCER_DAMAGE,
         },
+        resources::StageStatistics,
     },
     systems::camera::CameraPos,
 };
@@ -28,15 +30,32 @@ pub fn check_got_hit(
     mut event_writer: EventWriter<DamageEvent>,
     mut attack_query: Query<(&PlayerAttack, &mut UnhittableList)>,
     // mut attack_query: Query<(&PlayerAttack, &mut UnhittableList, Option<&Reach>)>,
-    mut hittable_query: Query<(Entity, &PxSubPosition, &ColliderData, &Depth), With<Hittable>>,
+    mut hittable_query: Query<
+        (
+            Entity,
+            &PxSubPosition,
+            &ColliderData,
+            &Depth,
+            Option<&PickupCollection>,
+        ),
+        With<Hittable>,
+    >,
     mut score: ResMut<Score>,
+    mut stats: ResMut<StageStatistics>,
 ) {
     let camera_pos = camera_query.get_single().unwrap();
     for (attack, mut hit_list) in attack_query.iter_mut() {
-        for (entity, position, collider_data, depth) in hittable_query.iter_mut() {
+        let mut landed = false;
+        for (entity, position, collider_data, depth, collection) in hittable_query.iter_mut() {
             if hit_list.0.contains(&entity) == false {
                 hit_list.0.insert(entity);
 
+                if let (Weapon::Gun, Some(PickupCollection::MeleeOnly)) =
+                    (attack.weapon, collection)
+                {
+                    continue;
+                }
+
                 let attack_position = camera_pos.0 + attack.position;
                 match attack.weapon {
                     Weapon::Pincer => {
@@ -47,8 +66,10 @@ pub fn check_got_hit(
                                 entity,
                                 (ATTACK_PINCER_DAMAGE as f32 / collider.defense) as u32,
                             ));
+                            landed = true;
                             if collider.defense <= CRITICAL_THRESHOLD {
                                 score.add_u(SCORE_MELEE_CRITICAL_HIT);
+                                stats.criticals += 1;
 
                                 #[cfg(debug_assertions)]
                                 println!("{} Pincer ***CRITICAL***", "HIT".yellow());
@@ -68,8 +89,10 @@ pub fn check_got_hit(
                                 entity,
                                 (ATTACK_GUN_DAMAGE as f32 / collider.defense) as u32,
                             ));
+                            landed = true;
                             if collider.defense <= CRITICAL_THRESHOLD {
                                 score.add_u(SCORE_RANGED_CRITICAL_HIT);
+                                stats.criticals += 1;
 
                                 #[cfg(debug_assertions)]
                                 println!("{} Gun ***CRITICAL***", "HIT".yellow());
@@ -84,5 +107,9 @@ pub fn check_got_hit(
                 }
             }
         }
+
+        if landed {
+            stats.attacks_hit += 1;
+        }
     }
 }
@@ -1,4 +1,7 @@
-use super::data::SkyboxData;
+use super::{
+    components::placement::Parallax,
+    data::{ParallaxLayer, SkyboxData},
+};
 use crate::layer::Layer;
 use bevy::prelude::*;
 use seldom_pixel::{asset::*, prelude::*};
@@ -55,3 +58,50 @@ impl SkyboxBundle {
         }
     }
 }
+
+#[derive(Bundle)]
+pub struct ParallaxLayerBundle {
+    pub name: Name,
+    pub parallax: Parallax,
+    pub position: PxSubPosition,
+    pub sprite: PxSpriteBundle<Layer>,
+}
+
+impl ParallaxLayerBundle {
+    pub fn new(
+        assets_sprite: &mut PxAssets<PxSprite>,
+        data: &ParallaxLayer,
+        camera_position: Vec2,
+    ) -> (Self, Option<PxAnimationBundle>) {
+        let sprite = match &data.animation {
+            Some(animation) => assets_sprite.load_animated(data.path.clone(), animation.frames),
+            None => assets_sprite.load(data.path.clone()),
+        };
+        let parallax = Parallax {
+            factor: data.factor,
+            autoscroll: data.autoscroll.unwrap_or(Vec2::ZERO),
+            offset: data.offset,
+            scrolled: Vec2::ZERO,
+        };
+        let animation = data.animation.as_ref().map(|animation| PxAnimationBundle {
+            duration: PxAnimationDuration::millis_per_animation(animation.speed),
+            on_finish: PxAnimationFinishBehavior::Loop,
+            ..default()
+        });
+
+        (
+            Self {
+                name: Name::new("ParallaxLayer"),
+                position: parallax.position(camera_position).into(),
+                parallax,
+                sprite: PxSpriteBundle::<Layer> {
+                    sprite,
+                    anchor: PxAnchor::BottomLeft,
+                    layer: data.layer.clone(),
+                    ..default()
+                },
+            },
+            animation,
+        )
+    }
+}
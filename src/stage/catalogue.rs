@@ -0,0 +1,222 @@
+use super::{
+    components::{
+        interactive::{Collider, ColliderData},
+        placement::Depth,
+    },
+    destructible::{components::DestructibleState, data::AnimationData},
+};
+use assert_assets_path::assert_assets_path;
+use bevy::{ecs::system::SystemParam, prelude::*, reflect::TypePath};
+use seldom_pixel::prelude::{PxAnchor, PxAnimationFinishBehavior};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub const OBJECT_CATALOGUE_PATH: &str = assert_assets_path!("objects/catalogue.objects.ron");
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Reflect, Serialize)]
+pub enum CatalogueAnchor {
+    #[default]
+    BottomCenter,
+    BottomLeft,
+    BottomRight,
+    Center,
+    CenterLeft,
+    CenterRight,
+    TopCenter,
+    TopLeft,
+    TopRight,
+}
+
+impl From<CatalogueAnchor> for PxAnchor {
+    fn from(anchor: CatalogueAnchor) -> Self {
+        match anchor {
+            CatalogueAnchor::BottomCenter => PxAnchor::BottomCenter,
+            CatalogueAnchor::BottomLeft => PxAnchor::BottomLeft,
+            CatalogueAnchor::BottomRight => PxAnchor::BottomRight,
+            CatalogueAnchor::Center => PxAnchor::Center,
+            CatalogueAnchor::CenterLeft => PxAnchor::CenterLeft,
+            CatalogueAnchor::CenterRight => PxAnchor::CenterRight,
+            CatalogueAnchor::TopCenter => PxAnchor::TopCenter,
+            CatalogueAnchor::TopLeft => PxAnchor::TopLeft,
+            CatalogueAnchor::TopRight => PxAnchor::TopRight,
+        }
+    }
+}
+
+fn default_frames() -> usize {
+    1
+}
+
+fn default_speed() -> u64 {
+    500
+}
+
+#[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
+pub struct CatalogueAnimation {
+    pub sprite_path: String,
+    #[serde(default = "default_frames")]
+    pub frames: usize,
+    /**
+     * Milliseconds per animation loop
+     */
+    #[serde(default = "default_speed")]
+    pub speed: u64,
+    #[serde(default)]
+    pub anchor: CatalogueAnchor,
+}
+
+impl CatalogueAnimation {
+    pub fn to_animation_data(
+        &self,
+        finish_behavior: PxAnimationFinishBehavior,
+        collider_data: ColliderData,
+    ) -> AnimationData {
+        AnimationData {
+            anchor: self.anchor.into(),
+            collider_data,
+            finish_behavior,
+            frames: self.frames,
+            speed: self.speed,
+            sprite_path: self.sprite_path.clone(),
+            ..default()
+        }
+    }
+}
+
+/**
+ * Static scenery, drawn but never hit
+ */
+#[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
+pub struct ObjectEntry {
+    pub sprite_path: String,
+    #[serde(default)]
+    pub anchor: CatalogueAnchor,
+    /**
+     * Depth the sprite was drawn for, used when placing new spawns
+     */
+    pub depth: Depth,
+}
+
+/**
+ * The sprites of a destructible as drawn for one depth
+ */
+#[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
+pub struct DestructibleVariant {
+    #[serde(default)]
+    pub colliders: Vec<Collider>,
+    pub base: CatalogueAnimation,
+    /**
+     * One per damage threshold, falling back to the base animation when missing
+     */
+    #[serde(default)]
+    pub damaged: Vec<CatalogueAnimation>,
+    pub broken: CatalogueAnimation,
+}
+
+impl DestructibleVariant {
+    pub fn by_state(&self, state: &DestructibleState) -> AnimationData {
+        let collider_data = ColliderData::from_many(self.colliders.clone());
+        match state {
+            DestructibleState::Base => self
+                .base
+                .to_animation_data(PxAnimationFinishBehavior::Loop, collider_data),
+            DestructibleState::Damaged(i) => self
+                .damaged
+                .get(*i as usize)
+                .unwrap_or(&self.base)
+                .to_animation_data(PxAnimationFinishBehavior::Loop, collider_data),
+            DestructibleState::Broken => self
+                .broken
+                .to_animation_data(PxAnimationFinishBehavior::Mark, ColliderData::new()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
+pub struct DestructibleEntry {
+    /**
+     * Depth of the default variant, used when placing new spawns
+     */
+    pub depth: Depth,
+    pub health: u32,
+    /**
+     * Fractions of the starting health under which each damaged state kicks in,
+     * from the least to the most damaged
+     */
+    #[serde(default)]
+    pub damage_thresholds: Vec<f32>,
+    #[serde(default)]
+    pub break_score: u32,
+    #[serde(default)]
+    pub debris_count: u8,
+    pub variants: HashMap<Depth, DestructibleVariant>,
+}
+
+impl DestructibleEntry {
+    pub fn get_variant(&self, depth: &Depth) -> Option<&DestructibleVariant> {
+        self.variants.get(depth)
+    }
+
+    pub fn state_from_health(&self, health: u32, max_health: u32) -> DestructibleState {
+        if health == 0 {
+            return DestructibleState::Broken;
+        }
+        let ratio = health as f32 / max_health.max(1) as f32;
+        let reached = self
+            .damage_thresholds
+            .iter()
+            .filter(|threshold| ratio <= **threshold)
+            .count();
+        match reached {
+            0 => DestructibleState::Base,
+            n => DestructibleState::Damaged((n - 1) as u8),
+        }
+    }
+}
+
+/**
+ * Scenery available to the stages, keyed by the ids their spawns reference
+ */
+#[derive(Asset, Clone, Debug, Default, Deserialize, Serialize, TypePath)]
+pub struct ObjectCatalogue {
+    #[serde(default)]
+    pub objects: HashMap<String, ObjectEntry>,
+    #[serde(default)]
+    pub destructibles: HashMap<String, DestructibleEntry>,
+}
+
+impl ObjectCatalogue {
+    pub fn get_object(&self, id: &str) -> Option<&ObjectEntry> {
+        self.objects.get(id)
+    }
+
+    pub fn get_destructible(&self, id: &str) -> Option<&DestructibleEntry> {
+        self.destructibles.get(id)
+    }
+}
+
+#[derive(Resource)]
+pub struct ObjectCatalogueHandle {
+    pub handle: Handle<ObjectCatalogue>,
+}
+
+#[derive(SystemParam)]
+pub struct ObjectCatalogueParam<'w> {
+    handle: Res<'w, ObjectCatalogueHandle>,
+    assets: Res<'w, Assets<ObjectCatalogue>>,
+}
+
+impl<'w> ObjectCatalogueParam<'w> {
+    /**
+     * None while the catalogue is still loading
+     */
+    pub fn get(&self) -> Option<&ObjectCatalogue> {
+        self.assets.get(&self.handle.handle)
+    }
+}
+
+pub fn load_object_catalogue(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(ObjectCatalogueHandle {
+        handle: asset_server.load::<ObjectCatalogue>(OBJECT_CATALOGUE_PATH),
+    });
+}
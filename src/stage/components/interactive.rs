@@ -1,11 +1,12 @@
 use bevy::prelude::*;
 use derive_more::From;
 use derive_new::new;
+use serde::{Deserialize, Serialize};
 
 #[derive(Component)]
 pub struct Object;
 
-#[derive(Clone, Copy, Debug, Reflect)]
+#[derive(Clone, Copy, Debug, Deserialize, Reflect, Serialize)]
 pub enum ColliderShape {
     Box(Vec2),
     Circle(f32),
@@ -21,15 +22,21 @@ impl ColliderShape {
     }
 }
 
-#[derive(new, Clone, Copy, Debug, From, Reflect)]
+#[derive(new, Clone, Copy, Debug, Deserialize, From, Reflect, Serialize)]
 pub struct Collider {
     pub shape: ColliderShape,
     #[new(value = "1.")]
+    #[serde(default = "default_defense")]
     pub defense: f32,
     #[new(default)]
+    #[serde(default)]
     pub offset: Vec2,
 }
 
+fn default_defense() -> f32 {
+    1.
+}
+
 impl Collider {
     pub fn new_circle(radius: f32) -> Self {
         Self::new(ColliderShape::Circle(radius))
@@ -1,10 +1,15 @@
 pub mod damage;
 pub mod interactive;
+pub mod movement;
 pub mod placement;
 
 use self::placement::Depth;
-use super::data::{ContainerSpawn, StageSpawn};
-use crate::cutscene::data::CutsceneAnimationsSpawn;
+use super::data::{ContainerSpawn, StageSpawn, GAME_BASE_SPEED};
+use crate::{
+    cutscene::data::CutsceneAnimationsSpawn,
+    letterbox::events::LetterboxMove,
+    plugins::movement::track::{Easing, Path, Track},
+};
 use bevy::{prelude::*, utils::HashMap};
 use derive_new::new;
 use serde::{Deserialize, Serialize};
@@ -57,15 +62,153 @@ impl StageElapse {
 #[derive(Component, Reflect)]
 pub struct StageElapsedStarted(pub Duration);
 
+#[serde_as]
 #[derive(Component, Clone, Debug, Deserialize, Reflect, Serialize)]
 pub enum CinematicStageStep {
+    /**
+     * Holds until the longest animation has played through once
+     */
     CutsceneAnimationSpawn(CutsceneAnimationsSpawn),
+    Dialogue(CinematicDialogue),
+    Elapse(#[serde_as(as = "DurationSecondsWithFrac")] Duration),
+    Scene(CinematicScene),
+}
+
+impl CinematicStageStep {
+    pub fn to_scene(&self) -> CinematicScene {
+        match self {
+            CinematicStageStep::CutsceneAnimationSpawn(x) => {
+                CinematicScene::new().with_animations(x.clone())
+            }
+            CinematicStageStep::Dialogue(x) => CinematicScene::new().with_dialogue(x.clone()),
+            CinematicStageStep::Elapse(x) => CinematicScene::new().with_duration(*x),
+            CinematicStageStep::Scene(x) => x.clone(),
+        }
+    }
+}
+
+fn default_line_duration() -> Duration {
+    Duration::from_secs(3)
+}
+
+#[serde_as]
+#[derive(new, Clone, Debug, Deserialize, Reflect, Serialize)]
+pub struct CinematicDialogue {
+    #[new(default)]
+    #[serde(default)]
+    pub speaker: Option<String>,
+    pub lines: Vec<String>,
+    /**
+     * How long each line stays up, unless it is skipped with A
+     */
+    #[new(value = "default_line_duration()")]
+    #[serde_as(as = "DurationSecondsWithFrac")]
+    #[serde(default = "default_line_duration")]
+    pub line_duration: Duration,
+}
+
+impl CinematicDialogue {
+    pub fn get_line(&self, index: usize) -> Option<String> {
+        self.lines.get(index).map(|line| match &self.speaker {
+            Some(speaker) => format!("{}: {}", speaker, line),
+            None => line.clone(),
+        })
+    }
+}
+
+/**
+ * Plays over the stage, done once the duration is over and the dialogue has been read through
+ */
+#[serde_as]
+#[derive(new, Clone, Debug, Default, Deserialize, Reflect, Serialize)]
+pub struct CinematicScene {
+    #[new(default)]
+    #[serde(default)]
+    pub animations: Option<CutsceneAnimationsSpawn>,
+    #[new(default)]
+    #[serde(default)]
+    pub dialogue: Option<CinematicDialogue>,
+    #[new(default)]
+    #[serde_as(as = "Option<DurationSecondsWithFrac>")]
+    #[serde(default)]
+    pub duration: Option<Duration>,
 }
 
+impl CinematicScene {
+    pub fn with_animations(mut self, value: CutsceneAnimationsSpawn) -> Self {
+        self.animations = Some(value);
+        self
+    }
+
+    pub fn with_dialogue(mut self, value: CinematicDialogue) -> Self {
+        self.dialogue = Some(value);
+        self
+    }
+
+    pub fn with_duration(mut self, value: Duration) -> Self {
+        self.duration = Some(value);
+        self
+    }
+
+    pub fn min_duration(&self) -> Duration {
+        let animations = self
+            .animations
+            .iter()
+            .flat_map(|x| x.spawns.iter())
+            .map(|x| x.duration)
+            .max()
+            .unwrap_or(Duration::ZERO);
+        animations.max(self.duration.unwrap_or(Duration::ZERO))
+    }
+
+    /**
+     * Assumes every line is left up for its whole duration
+     */
+    pub fn expected_duration(&self) -> Duration {
+        let dialogue = self
+            .dialogue
+            .as_ref()
+            .map(|x| x.line_duration * x.lines.len() as u32)
+            .unwrap_or(Duration::ZERO);
+        self.min_duration().max(dialogue)
+    }
+}
+
+/**
+ * Tracks the cinematic step playing on the stage
+ */
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct CinematicProgress {
+    pub scene: CinematicScene,
+    pub started: Duration,
+    pub line_index: usize,
+    pub line_started: Duration,
+}
+
+impl CinematicProgress {
+    pub fn current_line(&self) -> Option<String> {
+        self.scene
+            .dialogue
+            .as_ref()
+            .and_then(|x| x.get_line(self.line_index))
+    }
+
+    pub fn is_finished(&self, elapsed: Duration) -> bool {
+        self.current_line().is_none() && self.started + self.scene.min_duration() <= elapsed
+    }
+}
+
+#[derive(Component, Clone, Debug)]
+pub struct CinematicGraphic;
+
+#[derive(Component, Clone, Debug)]
+pub struct CinematicText;
+
 fn default_base_speed() -> f32 {
     1.0
 }
 
+#[serde_as]
 #[derive(new, Component, Clone, Debug, Deserialize, Reflect, Serialize)]
 pub struct MovementStageStep {
     #[new(default)]
@@ -73,16 +216,42 @@ pub struct MovementStageStep {
     #[new(value = "1.")]
     #[serde(default = "default_base_speed")]
     pub base_speed: f32,
+    /**
+     * Added to the base speed every second, ignored when easing
+     */
+    #[new(default)]
+    #[serde(default)]
+    pub acceleration: Option<f32>,
+    /**
+     * Takes precedence over the base speed and acceleration
+     */
+    #[new(default)]
+    #[serde_as(as = "Option<DurationSecondsWithFrac>")]
+    #[serde(default)]
+    pub duration: Option<Duration>,
+    #[new(default)]
+    #[serde(default)]
+    pub easing: Easing,
+    #[new(default)]
+    #[serde(default)]
+    pub path: Option<Path>,
     #[new(default)]
     #[serde(default)]
     pub spawns: Vec<StageSpawn>,
     #[new(default)]
     #[serde(default)]
     pub floor_depths: Option<HashMap<Depth, f32>>,
+    /**
+     * Brings the letterbox in or out when the step starts, for boss intros and the like
+     */
+    #[new(default)]
+    #[serde(default)]
+    pub letterbox: Option<LetterboxMove>,
+    #[new(default)]
+    #[serde(default)]
+    pub music: Option<StageMusicChange>,
     // TODO
     // pub is_checkpoint: bool,
-    // pub music_fade: bool,
-    // pub music_track: Option<String>,
 }
 
 impl MovementStageStep {
@@ -95,6 +264,11 @@ impl MovementStageStep {
         Self::new().with_coordinates(Vec2::new(x, y))
     }
 
+    pub fn with_acceleration(mut self, value: f32) -> Self {
+        self.acceleration = Some(value);
+        self
+    }
+
     pub fn with_base_speed(mut self, value: f32) -> Self {
         self.base_speed = value;
         self
@@ -105,10 +279,60 @@ impl MovementStageStep {
         self
     }
 
+    pub fn with_duration(mut self, value: Duration) -> Self {
+        self.duration = Some(value);
+        self
+    }
+
+    pub fn with_easing(mut self, value: Easing) -> Self {
+        self.easing = value;
+        self
+    }
+
     pub fn with_floor_depths(mut self, value: HashMap<Depth, f32>) -> Self {
         self.floor_depths = Some(value);
         self
     }
+
+    pub fn with_letterbox(mut self, value: LetterboxMove) -> Self {
+        self.letterbox = Some(value);
+        self
+    }
+
+    pub fn with_music(mut self, value: StageMusicChange) -> Self {
+        self.music = Some(value);
+        self
+    }
+
+    pub fn with_path(mut self, value: Path) -> Self {
+        self.path = Some(value);
+        self
+    }
+
+    /**
+     * Whether the camera can be moved by the straight linear bundles
+     */
+    pub fn is_linear(&self) -> bool {
+        self.path.is_none() && self.duration.is_none() && self.easing == Easing::Linear
+    }
+
+    pub fn track_from(&self, start: Vec2) -> Track {
+        let points = match &self.path {
+            Some(path) => path.sample(start, self.coordinates),
+            None => vec![start, self.coordinates],
+        };
+        let acceleration = match self.easing {
+            Easing::Linear => self.acceleration.map(|a| a * GAME_BASE_SPEED),
+            _ => None,
+        };
+        Track::new(
+            points,
+            self.base_speed * GAME_BASE_SPEED,
+            acceleration,
+            self.easing,
+            self.duration,
+        )
+    }
 }
 
 #[serde_as]
@@ -130,10 +354,17 @@ pub struct StopStageStep {
     #[new(default)]
     #[serde(default)]
     pub floor_depths: Option<HashMap<Depth, f32>>,
+    /**
+     * Brings the letterbox in or out when the step starts, for boss intros and the like
+     */
+    #[new(default)]
+    #[serde(default)]
+    pub letterbox: Option<LetterboxMove>,
+    #[new(default)]
+    #[serde(default)]
+    pub music: Option<StageMusicChange>,
     // TODO
     // pub is_checkpoint: bool,
-    // pub music_fade: bool,
-    // pub music_track: Option<String>,
 }
 
 impl StopStageStep {
@@ -161,4 +392,49 @@ impl StopStageStep {
         self.floor_depths = Some(value);
         self
     }
+
+    pub fn with_letterbox(mut self, value: LetterboxMove) -> Self {
+        self.letterbox = Some(value);
+        self
+    }
+
+    pub fn with_music(mut self, value: StageMusicChange) -> Self {
+        self.music = Some(value);
+        self
+    }
+}
+
+fn default_music_fade() -> Duration {
+    Duration::from_secs(2)
+}
+
+/**
+ * Applied to the stage music when the step starts
+ */
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
+pub enum StageMusicChange {
+    /**
+     * Crossfades from the current music into a new track
+     */
+    Track {
+        path: String,
+        #[serde_as(as = "DurationSecondsWithFrac")]
+        #[serde(default = "default_music_fade")]
+        fade: Duration,
+    },
+    FadeOut {
+        #[serde_as(as = "DurationSecondsWithFrac")]
+        #[serde(default = "default_music_fade")]
+        fade: Duration,
+    },
+    /**
+     * Brings the music to a fraction of its volume, 1.0 restores it
+     */
+    Duck {
+        volume: f32,
+        #[serde_as(as = "DurationSecondsWithFrac")]
+        #[serde(default = "default_music_fade")]
+        fade: Duration,
+    },
 }
@@ -0,0 +1,12 @@
+use crate::plugins::movement::track::Track;
+use bevy::prelude::*;
+use std::time::Duration;
+
+/**
+ * Drives the camera along a Track, for the movements the linear bundles can't describe
+ */
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct CameraPathMovement {
+    pub track: Track,
+    pub started: Duration,
+}
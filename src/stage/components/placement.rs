@@ -119,6 +119,23 @@ pub struct Floor(pub f32);
 #[derive(Component, Debug, Clone, Copy, Reflect)]
 pub struct Speed(pub f32);
 
+/**
+ * Positions the entity relative to the camera, by the factor it follows the world with
+ */
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct Parallax {
+    pub factor: f32,
+    pub autoscroll: Vec2,
+    pub offset: Vec2,
+    pub scrolled: Vec2,
+}
+
+impl Parallax {
+    pub fn position(&self, camera_position: Vec2) -> Vec2 {
+        camera_position * (1. - self.factor) + self.offset + self.scrolled
+    }
+}
+
 #[derive(Component, Debug)]
 pub struct InView;
 
@@ -3,7 +3,11 @@ use super::{
     destructible::data::DestructibleSpawn,
     enemy::{data::steps::EnemyStep, entity::EnemyType},
 };
-use crate::globals::{SCREEN_RESOLUTION, SCREEN_RESOLUTION_F32_H};
+use crate::{
+    globals::{SCREEN_RESOLUTION, SCREEN_RESOLUTION_F32_H},
+    layer::Layer,
+    transitions::data::TransitionData,
+};
 use bevy::{asset::Asset, prelude::*, reflect::Reflect};
 use derive_more::From;
 use derive_new::new;
@@ -28,19 +32,46 @@ pub struct SkyboxData {
     pub frames: usize,
 }
 
-// deriving Default for simplicity's sake in defining the stage data
 #[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
-pub enum ObjectType {
-    BenchBig,
-    BenchSmall,
-    Fibertree,
-    RugparkSign,
+pub struct ParallaxAnimationData {
+    pub frames: usize,
+    /**
+     * Milliseconds per animation loop
+     */
+    pub speed: u64,
+}
+
+fn default_parallax_factor() -> f32 {
+    1.
+}
+
+#[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
+pub struct ParallaxLayer {
+    pub path: String,
+    /**
+     * How much the layer follows the world as the camera moves.
+     * 1 moves along with the background, 0 stays fixed on screen like the skybox,
+     * anything in between drifts behind and above 1 rushes ahead.
+     */
+    #[serde(default = "default_parallax_factor")]
+    pub factor: f32,
+    /**
+     * Pixels per second the layer drifts on its own, e.g. clouds
+     */
+    #[serde(default)]
+    pub autoscroll: Option<Vec2>,
+    #[serde(default)]
+    pub animation: Option<ParallaxAnimationData>,
+    pub layer: Layer,
+    #[serde(default)]
+    pub offset: Vec2,
 }
 
 #[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
 pub enum PickupType {
     SmallHealthpack,
     BigHealthpack,
+    Bomb,
     // TODO
     // Weapon,
     // Ammo,
@@ -96,6 +127,14 @@ impl PickupSpawn {
             depth: Depth::Six,
         }
     }
+    pub fn bomb_base() -> Self {
+        Self {
+            pickup_type: PickupType::Bomb,
+            coordinates: Vec2::ZERO,
+            elapsed: Duration::ZERO,
+            depth: Depth::Six,
+        }
+    }
 }
 
 // TODO move pickup data under its own module?
@@ -117,7 +156,10 @@ impl PickupDropSpawn {
 
 #[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
 pub struct ObjectSpawn {
-    pub object_type: ObjectType,
+    /**
+     * Id of the entry in the object catalogue
+     */
+    pub id: String,
     pub coordinates: Vec2,
     pub depth: Depth,
 }
@@ -127,7 +169,7 @@ impl ObjectSpawn {
         Name::new(self.show_type())
     }
     pub fn show_type(&self) -> String {
-        format!("Object<{:?}>", self.object_type)
+        format!("Object<{}>", self.id)
     }
 
     pub fn with_coordinates(mut self, value: Vec2) -> Self {
@@ -137,7 +179,7 @@ impl ObjectSpawn {
 
     pub fn bench_big_base(x: f32, y: f32) -> Self {
         Self {
-            object_type: ObjectType::BenchBig,
+            id: "bench_big".to_string(),
             coordinates: Vec2::new(x, y),
             // TODO should be Six
             depth: Depth::Eight,
@@ -146,7 +188,7 @@ impl ObjectSpawn {
 
     pub fn bench_small_base(x: f32, y: f32) -> Self {
         Self {
-            object_type: ObjectType::BenchSmall,
+            id: "bench_small".to_string(),
             coordinates: Vec2::new(x, y),
             // TODO should be Six
             depth: Depth::Eight,
@@ -155,7 +197,7 @@ impl ObjectSpawn {
 
     pub fn fibertree_base(x: f32, y: f32) -> Self {
         Self {
-            object_type: ObjectType::Fibertree,
+            id: "fibertree".to_string(),
             coordinates: Vec2::new(x, y),
             depth: Depth::Two,
         }
@@ -163,7 +205,7 @@ impl ObjectSpawn {
 
     pub fn rugpark_sign_base(x: f32, y: f32) -> Self {
         Self {
-            object_type: ObjectType::RugparkSign,
+            id: "rugpark_sign".to_string(),
             coordinates: Vec2::new(x, y),
             depth: Depth::Three,
         }
@@ -180,6 +222,12 @@ pub struct EnemySpawn {
     #[reflect(ignore)]
     #[serde(default)]
     pub contains: Option<Box<ContainerSpawn>>,
+    /**
+     * Weighted loot rolled on death, on top of whatever it `contains`.
+     * Falls back to the enemy type's loot table when not set.
+     */
+    #[serde(default)]
+    pub drops: Option<Vec<(PickupType, f32)>>,
     pub coordinates: Vec2,
     pub speed: f32,
     #[serde(default)]
@@ -193,6 +241,8 @@ pub struct EnemyDropSpawn {
     #[reflect(ignore)]
     #[serde(default)]
     pub contains: Option<Box<ContainerSpawn>>,
+    #[serde(default)]
+    pub drops: Option<Vec<(PickupType, f32)>>,
     pub speed: f32,
     #[serde(default)]
     pub steps: VecDeque<EnemyStep>,
@@ -207,6 +257,7 @@ impl EnemyDropSpawn {
             speed: self.speed,
             steps: self.steps.clone(),
             contains: self.contains.clone(),
+            drops: self.drops.clone(),
             elapsed: Duration::ZERO,
         }
     }
@@ -254,6 +305,10 @@ impl EnemySpawn {
         self.contains = Some(Box::new(value));
         self
     }
+    pub fn with_loot(mut self, value: Vec<(PickupType, f32)>) -> Self {
+        self.drops = Some(value);
+        self
+    }
 
     pub fn add_step(mut self, value: EnemyStep) -> Self {
         self.steps.extend(vec![value]);
@@ -270,6 +325,7 @@ impl EnemySpawn {
             speed: 0.5,
             steps: vec![].into(),
             contains: None,
+            drops: None,
         }
     }
     pub fn mosquito_base() -> Self {
@@ -281,6 +337,7 @@ impl EnemySpawn {
             speed: 2.0,
             steps: vec![].into(),
             contains: None,
+            drops: None,
         }
     }
     pub fn mosquito_variant_circle() -> Self {
@@ -403,7 +460,33 @@ pub struct StageData {
     pub background_path: String,
     pub music_path: String,
     pub skybox: SkyboxData,
+    /**
+     * Extra scenery layers scrolling at their own pace around the background
+     */
+    #[serde(default)]
+    pub layers: Vec<ParallaxLayer>,
     pub start_coordinates: Option<Vec2>,
+    /**
+     * Seeds the loot rolls, random when not set
+     */
+    #[serde(default)]
+    pub loot_seed: Option<u64>,
     pub spawns: Vec<StageSpawn>,
     pub steps: Vec<StageStep>,
+    /**
+     * Played once the last step is cleared, the game waits on it before moving on
+     */
+    #[serde(default)]
+    pub clear_transition: Option<TransitionData>,
+    /**
+     * Played over the death and game over screens, so it has to be an Out phase,
+     * an In phase would leave them covered
+     */
+    #[serde(default)]
+    pub death_transition: Option<TransitionData>,
+    /**
+     * Raised in the GameFlags once the stage is cleared
+     */
+    #[serde(default)]
+    pub clear_flags: Vec<String>,
 }
@@ -1,4 +1,4 @@
-use super::data::destructibles::DestructibleAnimationData;
+use super::data::AnimationData;
 use crate::{
     layer::Layer,
     stage::components::{interactive::ColliderData, placement::Depth},
@@ -9,47 +9,64 @@ use seldom_pixel::{
     sprite::{PxSprite, PxSpriteBundle},
 };
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::time::Duration;
 
 #[derive(Component)]
 pub struct Destructible;
 
-#[derive(Clone, Component, Debug, Deserialize, Reflect, Serialize)]
-pub enum DestructibleType {
-    Lamp,
-    Trashcan,
-    Crystal,
-    Mushroom,
-    // Window,
-    // Plant,
-}
+/**
+ * Id of the destructible's entry in the object catalogue
+ */
+#[derive(Clone, Component, Debug, Reflect)]
+pub struct DestructibleId(pub String);
 
+#[derive(Clone, Component, Copy, Debug, Default, Eq, PartialEq, Reflect)]
 pub enum DestructibleState {
+    #[default]
     Base,
+    Damaged(u8),
     Broken,
 }
 
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+pub struct DestructibleMaxHealth(pub u32);
+
+/**
+ * Damages the enemies around the destructible when it breaks
+ */
+#[derive(Clone, Component, Copy, Debug, Deserialize, Reflect, Serialize)]
+pub struct DestructibleExplosion {
+    pub radius: f32,
+    pub damage: u32,
+}
+
+pub const DEBRIS_GRAVITY: f32 = 200.;
+pub const DEBRIS_LIFETIME_SECS: f32 = 0.8;
+pub const DEBRIS_MAX_SPEED_X: f32 = 40.;
+pub const DEBRIS_MIN_SPEED_Y: f32 = 30.;
+pub const DEBRIS_MAX_SPEED_Y: f32 = 80.;
+
+#[derive(Clone, Component, Debug, Reflect)]
+pub struct DestructibleDebris {
+    pub velocity: Vec2,
+    pub elapsed: Duration,
+}
+
 pub fn make_animation_bundle(
     assets_sprite: &mut PxAssets<PxSprite>,
-    animation_map: &HashMap<Depth, DestructibleAnimationData>,
-    destructible_state: &DestructibleState,
+    animation_data: &AnimationData,
     depth: &Depth,
-) -> Option<(PxSpriteBundle<Layer>, PxAnimationBundle, ColliderData)> {
-    animation_map
-        .get(depth)
-        .map(|data| data.by_state(destructible_state))
-        .map(|animation_data| {
-            let sprite = assets_sprite
-                .load_animated(animation_data.sprite_path.clone(), animation_data.frames);
-            (
-                PxSpriteBundle::<Layer> {
-                    sprite,
-                    layer: depth.to_layer(),
-                    anchor: animation_data.anchor,
-                    ..default()
-                },
-                animation_data.make_animation_bundle(),
-                animation_data.collider_data.clone(),
-            )
-        })
+) -> (PxSpriteBundle<Layer>, PxAnimationBundle, ColliderData) {
+    let sprite =
+        assets_sprite.load_animated(animation_data.sprite_path.clone(), animation_data.frames);
+    (
+        PxSpriteBundle::<Layer> {
+            sprite,
+            layer: depth.to_layer(),
+            anchor: animation_data.anchor,
+            ..default()
+        },
+        animation_data.make_animation_bundle(),
+        animation_data.collider_data.clone(),
+    )
 }
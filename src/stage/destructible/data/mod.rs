@@ -1,6 +1,4 @@
-pub mod destructibles;
-
-use super::components::DestructibleType;
+use super::components::DestructibleExplosion;
 use crate::stage::{
     components::{interactive::ColliderData, placement::Depth},
     data::ContainerSpawn,
@@ -19,8 +17,17 @@ pub struct DestructibleSpawn {
     pub contains: Option<Box<ContainerSpawn>>,
     pub coordinates: Vec2,
     pub depth: Depth,
-    pub destructible_type: DestructibleType,
-    pub health: u32,
+    /**
+     * Id of the entry in the object catalogue
+     */
+    pub id: String,
+    #[serde(default)]
+    pub explosion: Option<DestructibleExplosion>,
+    /**
+     * Overrides the catalogue's health when set
+     */
+    #[serde(default)]
+    pub health: Option<u32>,
 }
 
 pub enum LampDepth {
@@ -74,13 +81,37 @@ impl CrystalDepth {
     }
 }
 
+pub enum WindowDepth {
+    Five,
+}
+
+impl WindowDepth {
+    pub fn to_depth(&self) -> Depth {
+        match self {
+            WindowDepth::Five => Depth::Five,
+        }
+    }
+}
+
+pub enum PlantDepth {
+    Four,
+}
+
+impl PlantDepth {
+    pub fn to_depth(&self) -> Depth {
+        match self {
+            PlantDepth::Four => Depth::Four,
+        }
+    }
+}
+
 impl DestructibleSpawn {
     pub fn get_name(&self) -> Name {
         Name::new(self.show_type())
     }
     // TODO could use a Spawn trait
     pub fn show_type(&self) -> String {
-        format!("Destructible<{:?}>", self.destructible_type)
+        format!("Destructible<{}>", self.id)
     }
 
     pub fn with_coordinates(mut self, value: Vec2) -> Self {
@@ -99,7 +130,7 @@ impl DestructibleSpawn {
     }
 
     pub fn with_health(mut self, value: u32) -> Self {
-        self.health = value;
+        self.health = Some(value);
         self
     }
 
@@ -108,12 +139,18 @@ impl DestructibleSpawn {
         self
     }
 
+    pub fn with_explosion(mut self, radius: f32, damage: u32) -> Self {
+        self.explosion = Some(DestructibleExplosion { radius, damage });
+        self
+    }
+
     pub fn lamp_base(x: f32, y: f32, depth: LampDepth) -> Self {
         Self {
             contains: None,
+            explosion: None,
             coordinates: Vec2::new(x, y),
-            destructible_type: DestructibleType::Lamp,
-            health: 60,
+            id: "lamp".to_string(),
+            health: None,
             depth: depth.to_depth(),
         }
     }
@@ -123,30 +160,55 @@ impl DestructibleSpawn {
      */
     pub fn trashcan_base(x: f32, y: f32, depth: TrashcanDepth) -> Self {
         Self {
-            destructible_type: DestructibleType::Trashcan,
+            id: "trashcan".to_string(),
             coordinates: Vec2::new(x, y),
             contains: None,
-            health: 100,
+            explosion: None,
+            health: None,
             depth: depth.to_depth(),
         }
     }
 
     pub fn crystal_base(x: f32, y: f32, depth: CrystalDepth) -> Self {
         Self {
-            destructible_type: DestructibleType::Crystal,
+            id: "crystal".to_string(),
             coordinates: Vec2::new(x, y),
             contains: None,
-            health: 300,
+            explosion: None,
+            health: None,
             depth: depth.to_depth(),
         }
     }
 
     pub fn mushroom_base(x: f32, y: f32, depth: MushroomDepth) -> Self {
         Self {
-            destructible_type: DestructibleType::Mushroom,
+            id: "mushroom".to_string(),
+            coordinates: Vec2::new(x, y),
+            contains: None,
+            explosion: None,
+            health: None,
+            depth: depth.to_depth(),
+        }
+    }
+
+    pub fn window_base(x: f32, y: f32, depth: WindowDepth) -> Self {
+        Self {
+            id: "window".to_string(),
+            coordinates: Vec2::new(x, y),
+            contains: None,
+            explosion: None,
+            health: None,
+            depth: depth.to_depth(),
+        }
+    }
+
+    pub fn plant_base(x: f32, y: f32, depth: PlantDepth) -> Self {
+        Self {
+            id: "plant".to_string(),
             coordinates: Vec2::new(x, y),
             contains: None,
-            health: 120,
+            explosion: None,
+            health: None,
             depth: depth.to_depth(),
         }
     }
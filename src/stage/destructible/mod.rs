@@ -2,7 +2,9 @@ pub mod components;
 pub mod data;
 mod systems;
 
-use self::systems::check_dead_destructible;
+use self::systems::{
+    check_dead_destructible, check_destructible_damage, update_destructible_debris,
+};
 use bevy::prelude::*;
 
 pub struct DestructiblePlugin;
@@ -12,7 +14,12 @@ impl Plugin for DestructiblePlugin {
         app.init_state::<DestructiblePluginUpdateState>()
             .add_systems(
                 Update,
-                (check_dead_destructible).run_if(in_state(DestructiblePluginUpdateState::Active)),
+                (
+                    check_destructible_damage,
+                    check_dead_destructible,
+                    update_destructible_debris,
+                )
+                    .run_if(in_state(DestructiblePluginUpdateState::Active)),
             );
     }
 }
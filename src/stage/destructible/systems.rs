@@ -1,38 +1,206 @@
-use super::{
-    components::{make_animation_bundle, Destructible, DestructibleState, DestructibleType},
-    data::destructibles::DESTRUCTIBLE_ANIMATIONS,
+use super::components::{
+    make_animation_bundle, Destructible, DestructibleDebris, DestructibleExplosion, DestructibleId,
+    DestructibleMaxHealth, DestructibleState, DEBRIS_GRAVITY, DEBRIS_LIFETIME_SECS,
+    DEBRIS_MAX_SPEED_X, DEBRIS_MAX_SPEED_Y, DEBRIS_MIN_SPEED_Y,
 };
-use crate::stage::components::{
-    interactive::{Dead, Flickerer, Hittable},
-    placement::Depth,
+use crate::{
+    components::{AudioSystemBundle, AudioSystemType, DespawnMark, VolumeSettings},
+    game::score::components::Score,
+    layer::Layer,
+    stage::{
+        catalogue::ObjectCatalogueParam,
+        components::{
+            interactive::{Dead, Flickerer, Health, Hittable},
+            placement::Depth,
+        },
+        enemy::components::Enemy,
+        events::DamageEvent,
+        player::events::CameraShakeTrigger,
+        resources::StageTime,
+    },
 };
-use bevy::prelude::*;
+use assert_assets_path::assert_assets_path;
+use bevy::{audio::PlaybackMode, prelude::*};
+use rand::Rng;
 use seldom_pixel::{prelude::*, sprite::PxSprite};
 
+fn spawn_debris(
+    commands: &mut Commands,
+    assets_sprite: &mut PxAssets<PxSprite>,
+    position: Vec2,
+    depth: &Depth,
+    count: u8,
+) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..count {
+        let sprite = assets_sprite.load(assert_assets_path!("sprites/objects/debris.png"));
+        let velocity = Vec2::new(
+            rng.gen_range(-DEBRIS_MAX_SPEED_X..=DEBRIS_MAX_SPEED_X),
+            rng.gen_range(DEBRIS_MIN_SPEED_Y..=DEBRIS_MAX_SPEED_Y),
+        );
+        let offset = Vec2::new(rng.gen_range(-4.0..=4.0), rng.gen_range(4.0..=12.0));
+        commands.spawn((
+            Name::new("DestructibleDebris"),
+            DestructibleDebris {
+                velocity,
+                elapsed: default(),
+            },
+            PxSpriteBundle::<Layer> {
+                sprite,
+                anchor: PxAnchor::Center,
+                layer: depth.to_layer(),
+                ..default()
+            },
+            PxSubPosition::from(position + offset),
+        ));
+    }
+}
+
+pub fn check_destructible_damage(
+    mut commands: Commands,
+    mut assets_sprite: PxAssets<PxSprite>,
+    catalogue: ObjectCatalogueParam,
+    mut query: Query<
+        (
+            Entity,
+            &DestructibleId,
+            &mut DestructibleState,
+            &Health,
+            &DestructibleMaxHealth,
+            &PxSubPosition,
+            &Depth,
+        ),
+        (With<Destructible>, Changed<Health>, Without<Dead>),
+    >,
+) {
+    let Some(catalogue) = catalogue.get() else {
+        return;
+    };
+    for (entity, id, mut state, health, max_health, position, depth) in query.iter_mut() {
+        let Some(entry) = catalogue.get_destructible(&id.0) else {
+            continue;
+        };
+        let next = entry.state_from_health(health.0, max_health.0);
+        if next == *state || next == DestructibleState::Broken {
+            continue;
+        }
+        *state = next;
+
+        if let Some(variant) = entry.get_variant(depth) {
+            commands
+                .entity(entity)
+                .insert(make_animation_bundle(
+                    &mut assets_sprite,
+                    &variant.by_state(&next),
+                    depth,
+                ))
+                .insert(PxSubPosition::from(position.0));
+        }
+
+        spawn_debris(
+            &mut commands,
+            &mut assets_sprite,
+            position.0,
+            depth,
+            entry.debris_count / 2,
+        );
+    }
+}
+
 pub fn check_dead_destructible(
     mut commands: Commands,
     mut assets_sprite: PxAssets<PxSprite>,
+    mut score: ResMut<Score>,
+    mut event_writer: EventWriter<DamageEvent>,
+    catalogue: ObjectCatalogueParam,
     query: Query<
-        (Entity, &DestructibleType, &PxSubPosition, &Depth),
+        (
+            Entity,
+            &DestructibleId,
+            &PxSubPosition,
+            &Depth,
+            Option<&DestructibleExplosion>,
+        ),
         (With<Destructible>, Added<Dead>),
     >,
+    enemy_query: Query<(Entity, &PxSubPosition), (With<Enemy>, Without<Dead>)>,
+    asset_server: Res<AssetServer>,
+    volume_settings: Res<VolumeSettings>,
 ) {
-    for (entity, destructible_type, position, depth) in query.iter() {
+    let Some(catalogue) = catalogue.get() else {
+        return;
+    };
+    for (entity, id, position, depth, explosion) in query.iter() {
         // TODO Should I do a bundle?
         let mut entity_commands = commands.entity(entity);
-        entity_commands.remove::<(Hittable, Flickerer, Destructible)>();
+        entity_commands
+            .remove::<(Hittable, Flickerer, Destructible)>()
+            .insert(DestructibleState::Broken);
+
+        let Some(entry) = catalogue.get_destructible(&id.0) else {
+            continue;
+        };
+        if let Some(variant) = entry.get_variant(depth) {
+            entity_commands
+                .insert(make_animation_bundle(
+                    &mut assets_sprite,
+                    &variant.by_state(&DestructibleState::Broken),
+                    depth,
+                ))
+                .insert(PxSubPosition::from(position.0));
+        }
 
-        let animations_map = &DESTRUCTIBLE_ANIMATIONS.get_animation_data(destructible_type);
-        let animation_bundle_o = make_animation_bundle(
+        score.add_u(entry.break_score);
+
+        spawn_debris(
+            &mut commands,
             &mut assets_sprite,
-            animations_map,
-            &DestructibleState::Broken,
+            position.0,
             depth,
+            entry.debris_count,
         );
-        if let Some(animation_bundle) = animation_bundle_o {
-            entity_commands
-                .insert(animation_bundle)
-                .insert(PxSubPosition::from(position.0));
+
+        if let Some(explosion) = explosion {
+            for (enemy, enemy_position) in enemy_query.iter() {
+                if enemy_position.0.distance(position.0) <= explosion.radius {
+                    event_writer.send(DamageEvent::new(enemy, explosion.damage));
+                }
+            }
+
+            commands.spawn((
+                AudioBundle {
+                    source: asset_server.load(assert_assets_path!("audio/sfx/bomb_explode.ogg")),
+                    settings: PlaybackSettings {
+                        mode: PlaybackMode::Despawn,
+                        volume: volume_settings.sfx.clone(),
+                        ..default()
+                    },
+                    ..default()
+                },
+                AudioSystemBundle {
+                    system_type: AudioSystemType::SFX,
+                },
+            ));
+
+            commands.trigger(CameraShakeTrigger);
         }
     }
 }
+
+pub fn update_destructible_debris(
+    mut commands: Commands,
+    stage_time: Res<StageTime>,
+    mut query: Query<(Entity, &mut DestructibleDebris, &mut PxSubPosition)>,
+) {
+    let delta = stage_time.delta.as_secs_f32();
+    for (entity, mut debris, mut position) in query.iter_mut() {
+        debris.elapsed += stage_time.delta;
+        if debris.elapsed.as_secs_f32() >= DEBRIS_LIFETIME_SECS {
+            commands.entity(entity).insert(DespawnMark);
+            continue;
+        }
+
+        debris.velocity.y -= DEBRIS_GRAVITY * delta;
+        position.0 += debris.velocity * delta;
+    }
+}
@@ -1,7 +1,21 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Debug, Default, Deserialize, Reflect, Serialize)]
+#[derive(
+    Clone,
+    Component,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Reflect,
+    Serialize,
+)]
 pub enum EnemyType {
     #[default]
     Mosquito,
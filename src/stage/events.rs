@@ -17,6 +17,12 @@ pub struct NextStepEvent;
 #[derive(Clone, Event)]
 pub struct StageClearedTrigger;
 
+/**
+ * Tears the stage down, leaving it ready for the next startup
+ */
+#[derive(Event)]
+pub struct StageShutdownTrigger;
+
 #[derive(Event)]
 pub struct StageDeathEvent;
 
@@ -1,5 +1,6 @@
 pub mod attack;
 pub mod bundles;
+pub mod catalogue;
 pub mod components;
 pub mod data;
 pub mod destructible;
@@ -13,20 +14,32 @@ pub mod ui;
 
 use self::{
     attack::AttackPlugin,
+    catalogue::{load_object_catalogue, ObjectCatalogue},
     components::placement::RailPosition,
     destructible::DestructiblePlugin,
     enemy::EnemyPlugin,
     events::*,
-    pickup::systems::health::pickup_health,
+    pickup::{
+        loot::LootRng,
+        systems::{
+            bomb::pickup_bomb,
+            health::pickup_health,
+            lifetime::{update_pickup_fall, update_pickup_lifetime},
+        },
+    },
     player::PlayerPlugin,
-    resources::{StageActionTimer, StageProgress, StageTime},
+    resources::{StageActionTimer, StageProgress, StageStatistics, StageTime},
     systems::{
         camera::*,
+        cinematic::*,
         damage::*,
+        letterbox::check_step_letterbox,
         movement::*,
-        setup::on_stage_startup,
-        spawn::{check_dead_drop, check_step_spawn, on_stage_spawn},
+        music::check_step_music,
+        setup::{on_stage_shutdown, on_stage_startup},
+        spawn::{check_dead_drop, check_dead_loot, check_step_spawn, on_stage_spawn},
         state::{on_active, on_inactive},
+        stats::*,
         *,
     },
     ui::{
@@ -42,6 +55,8 @@ use crate::{
         event::on_trigger_write_event,
         time::{tick_time, TimeMultiplier},
     },
+    cutscene::systems::tweens::update_sprite_tweens,
+    game::GameProgressState,
     globals::mark_for_despawn_by_query_system,
     plugins::movement::{
         linear::{
@@ -49,8 +64,12 @@ use crate::{
             LinearMovement2DPlugin, LinearMovementPlugin,
         },
         pursue::PursueMovementPlugin,
+        MovementSystems,
+    },
+    systems::{
+        audio::update_volume_fade, check_despawn_after_delay, delay_despawn,
+        tween::update_tween_path,
     },
-    systems::{check_despawn_after_delay, delay_despawn},
 };
 use bevy::prelude::*;
 use bevy_common_assets::ron::RonAssetPlugin;
@@ -73,15 +92,19 @@ pub struct StagePlugin;
  */
 impl Plugin for StagePlugin {
     fn build(&self, app: &mut App) {
-        #[cfg(debug_assertions)]
-        app.insert_resource(TimeMultiplier::<StageTime>::new(1.));
-
-        app.add_plugins(RonAssetPlugin::<StageData>::new(&["sg.ron"]))
+        app.insert_resource(TimeMultiplier::<StageTime>::new(1.))
+            .add_plugins(RonAssetPlugin::<StageData>::new(&["sg.ron"]))
+            .add_plugins(RonAssetPlugin::<ObjectCatalogue>::new(&["objects.ron"]))
             .init_state::<StagePluginUpdateState>()
             .init_state::<StageProgressState>()
+            .add_sub_state::<StageGameplayState>()
             .init_resource::<StageActionTimer>()
             .init_resource::<StageTime>()
             .init_resource::<StageProgress>()
+            .init_resource::<StageStatistics>()
+            .init_resource::<LootRng>()
+            .add_systems(PreStartup, load_object_catalogue)
+            .add_systems(Startup, load_stage_records)
             .add_event::<DamageEvent>()
             .add_event::<DepthChangedEvent>()
             .add_event::<StageDeathEvent>()
@@ -92,6 +115,8 @@ impl Plugin for StagePlugin {
             .observe(on_next_step_cleanup_stop_step)
             .add_event::<StageStartupTrigger>()
             .observe(on_stage_startup)
+            .add_event::<StageShutdownTrigger>()
+            .observe(on_stage_shutdown)
             .add_event::<StageSpawnTrigger>()
             .observe(on_stage_spawn)
             .add_event::<StageClearedTrigger>()
@@ -100,6 +125,22 @@ impl Plugin for StagePlugin {
             // TODO .observe(on_startup_from_checkpoint))
             .add_systems(OnEnter(StagePluginUpdateState::Active), on_active)
             .add_systems(OnEnter(StagePluginUpdateState::Inactive), on_inactive)
+            // Only back from a cinematic, not when the whole stage is shutting down
+            .add_systems(
+                OnExit(StageGameplayState::Cinematic),
+                on_active.run_if(in_state(StagePluginUpdateState::Active)),
+            )
+            .add_systems(OnEnter(StageGameplayState::Cinematic), on_inactive)
+            .configure_sets(
+                PreUpdate,
+                MovementSystems::<StageTime>::default()
+                    .run_if(not(in_state(StageGameplayState::Cinematic))),
+            )
+            .configure_sets(
+                Update,
+                MovementSystems::<StageTime>::default()
+                    .run_if(not(in_state(StageGameplayState::Cinematic))),
+            )
             .add_plugins(PursueMovementPlugin::<StageTime, RailPosition>::default())
             .add_plugins(PursueMovementPlugin::<StageTime, PxSubPosition>::default())
             .add_plugins(LinearMovementPlugin::<StageTime, TargetingPositionX>::default())
@@ -124,12 +165,21 @@ impl Plugin for StagePlugin {
                             // Camera
                             check_in_view,
                             check_outside_view,
+                            update_camera_path_movement
+                                .before(update_camera_pos_x)
+                                .before(update_camera_pos_y),
                             update_camera_pos_x,
                             update_camera_pos_y,
+                            update_parallax
+                                .after(update_camera_pos_x)
+                                .after(update_camera_pos_y),
                         ),
                         (
                             // Pickup
                             pickup_health,
+                            pickup_bomb,
+                            update_pickup_lifetime,
+                            update_pickup_fall,
                             mark_for_despawn_by_query_system::<PickupDespawnFilter>,
                         ),
                         (
@@ -139,13 +189,23 @@ impl Plugin for StagePlugin {
                             read_step_trigger,
                             check_stage_step_timer,
                             check_staged_cleared,
-                            check_step_spawn,
+                            check_step_spawn.run_if(in_state(StageGameplayState::Active)),
                             check_stage_death,
                         ),
+                        (
+                            // Stats
+                            count_attacks_fired,
+                            count_kills,
+                            count_damage_taken,
+                            count_pickups_collected,
+                        ),
                         (
                             // Effects
                             delay_despawn::<StageTime>,
                             check_despawn_after_delay::<StageTime>,
+                            update_volume_fade::<StageTime>,
+                            update_tween_path::<StageTime>,
+                            update_sprite_tweens::<StageTime>,
                         ),
                         (
                             // Movement
@@ -166,6 +226,7 @@ impl Plugin for StagePlugin {
                             add_invert_filter,
                             remove_invert_filter,
                             check_dead_drop,
+                            check_dead_loot,
                         ),
                         (
                             (
@@ -174,9 +235,13 @@ impl Plugin for StagePlugin {
                                 initialise_stop_step,
                             ),
                             (
-                                update_cinematic_step,
+                                // Reads A for the dialogue, which would also pick pause entries
+                                update_cinematic_step
+                                    .run_if(not(in_state(GameProgressState::Paused))),
                                 check_stop_step_finished_by_duration,
                                 check_movement_step_reached,
+                                check_step_music,
+                                check_step_letterbox,
                             ),
                         )
                             .chain(),
@@ -199,7 +264,7 @@ impl Plugin for StagePlugin {
                     despawn_game_over_screen,
                     // Pause menu
                     pause_menu_renderer,
-                    toggle_game,
+                    toggle_game.run_if(in_state(StageProgressState::Running)),
                 )
                     .run_if(in_state(StagePluginUpdateState::Active)),
             );
@@ -223,3 +288,15 @@ pub enum StagePluginUpdateState {
     Inactive,
     Active,
 }
+
+/**
+ * Enemies, attacks, the player and everything moving on the stage clock hold still
+ * while a cinematic step plays
+ */
+#[derive(SubStates, Debug, Clone, Eq, PartialEq, Hash, Default)]
+#[source(StagePluginUpdateState = StagePluginUpdateState::Active)]
+pub enum StageGameplayState {
+    #[default]
+    Active,
+    Cinematic,
+}
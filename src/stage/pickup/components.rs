@@ -1,4 +1,6 @@
+use crate::stage::data::PickupType;
 use bevy::prelude::*;
+use std::time::Duration;
 
 #[derive(Component, Debug, Clone, Reflect)]
 pub struct HealthRecovery(pub u32);
@@ -9,8 +11,96 @@ impl HealthRecovery {
     }
 }
 
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct BombSupply(pub u8);
+
 #[derive(Clone, Component, Debug, Default)]
 pub struct PickupFeedback;
 
 pub const PICKUP_FEEDBACK_TIME: f32 = 0.6;
 pub const PICKUP_FEEDBACK_INITIAL_SPEED_Y: f32 = 100.0;
+/** Matching the icons on the HUD */
+pub const PICKUP_FEEDBACK_HEALTH_TARGET: Vec2 = Vec2::new(12., 8.);
+pub const PICKUP_FEEDBACK_BOMB_TARGET: Vec2 = Vec2::new(60., 8.);
+
+pub const PICKUP_LIFETIME_SECS: f32 = 10.;
+pub const PICKUP_WARNING_SECS: f32 = 3.;
+pub const PICKUP_BLINK_SECS: f32 = 0.15;
+
+/**
+ * How long a dropped pickup stays around before vanishing, blinking during its last seconds.
+ * Only counts down while the pickup is in view
+ */
+#[derive(Clone, Component, Debug, Reflect)]
+pub struct PickupLifetime {
+    pub elapsed: Duration,
+    pub duration: Duration,
+}
+
+impl Default for PickupLifetime {
+    fn default() -> Self {
+        Self {
+            elapsed: Duration::ZERO,
+            duration: Duration::from_secs_f32(PICKUP_LIFETIME_SECS),
+        }
+    }
+}
+
+impl PickupLifetime {
+    pub fn remaining(&self) -> Duration {
+        self.duration.saturating_sub(self.elapsed)
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    pub fn is_warning(&self) -> bool {
+        self.remaining().as_secs_f32() <= PICKUP_WARNING_SECS
+    }
+
+    pub fn is_blink_visible(&self) -> bool {
+        (self.remaining().as_secs_f32() / PICKUP_BLINK_SECS) as u32 % 2 == 0
+    }
+}
+
+pub const PICKUP_GRAVITY: f32 = 240.;
+pub const PICKUP_DRIFT_DAMPING: f32 = 3.;
+pub const PICKUP_DROP_SPEED_Y: f32 = 50.;
+pub const PICKUP_DROP_MAX_DRIFT: f32 = 30.;
+
+/**
+ * Falls onto the floor of its depth, drifting sideways until it settles.
+ * Pickups in depths without a floor stay where they were dropped.
+ */
+#[derive(Clone, Component, Debug, Default, Reflect)]
+pub struct PickupFall {
+    pub velocity: Vec2,
+}
+
+impl PickupFall {
+    pub fn dropped(drift: f32) -> Self {
+        Self {
+            velocity: Vec2::new(drift, PICKUP_DROP_SPEED_Y),
+        }
+    }
+}
+
+/**
+ * Which of the player's weapons can collect the pickup
+ */
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+pub enum PickupCollection {
+    AnyWeapon,
+    MeleeOnly,
+}
+
+impl PickupCollection {
+    pub fn from_pickup_type(pickup_type: &PickupType) -> Self {
+        match pickup_type {
+            PickupType::SmallHealthpack => Self::AnyWeapon,
+            PickupType::BigHealthpack => Self::MeleeOnly,
+            PickupType::Bomb => Self::MeleeOnly,
+        }
+    }
+}
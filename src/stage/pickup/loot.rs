@@ -0,0 +1,76 @@
+use crate::{
+    game::resources::Difficulty,
+    stage::{data::PickupType, enemy::entity::EnemyType},
+};
+use bevy::prelude::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/**
+ * Pickups paired with their chance of dropping, out of 1.
+ * At most one of them drops per roll.
+ */
+#[derive(Clone, Component, Debug, Default, Reflect)]
+pub struct LootTable(pub Vec<(PickupType, f32)>);
+
+impl LootTable {
+    pub fn from_enemy_type(enemy_type: &EnemyType) -> Self {
+        Self(match enemy_type {
+            EnemyType::Mosquito => vec![
+                (PickupType::SmallHealthpack, 0.08),
+                (PickupType::Bomb, 0.02),
+            ],
+            EnemyType::Tardigrade => vec![
+                (PickupType::SmallHealthpack, 0.12),
+                (PickupType::BigHealthpack, 0.04),
+                (PickupType::Bomb, 0.03),
+            ],
+            EnemyType::Kyle | EnemyType::Marauder | EnemyType::Spidey | EnemyType::Spidomonsta => {
+                vec![]
+            }
+        })
+    }
+
+    /**
+     * The chances are scaled by the multiplier, so the table should leave room for it
+     */
+    pub fn roll(&self, rng: &mut impl Rng, multiplier: f32) -> Option<PickupType> {
+        let roll = rng.gen::<f32>();
+        let mut accumulated = 0.;
+        for (pickup_type, chance) in self.0.iter() {
+            accumulated += chance * multiplier;
+            if roll < accumulated {
+                return Some(pickup_type.clone());
+            }
+        }
+        None
+    }
+}
+
+pub fn difficulty_loot_multiplier(difficulty: Difficulty) -> f32 {
+    match difficulty {
+        Difficulty::Easy => 1.5,
+        Difficulty::Normal => 1.,
+        Difficulty::Hard => 0.6,
+    }
+}
+
+/**
+ * Seeded from the stage data when set, so that runs can be replayed
+ */
+#[derive(Resource)]
+pub struct LootRng(pub StdRng);
+
+impl LootRng {
+    pub fn new(seed: Option<u64>) -> Self {
+        Self(match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        })
+    }
+}
+
+impl Default for LootRng {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
@@ -1,4 +1,5 @@
 pub mod components;
+pub mod loot;
 pub mod systems;
 
 // TODO PickupBundle
@@ -0,0 +1,75 @@
+use super::health::{
+    PickupFeedbackBundle, PickupFeedbackDefaultBundle, PickupFeedbackMovementBundle,
+};
+use crate::{
+    components::{AudioSystemBundle, AudioSystemType, DespawnMark, VolumeSettings},
+    layer::Layer,
+    stage::{
+        components::interactive::Dead,
+        pickup::components::{BombSupply, PickupFeedback, PICKUP_FEEDBACK_BOMB_TARGET},
+        player::components::{BombStock, Player, PLAYER_MAX_BOMBS},
+    },
+    systems::camera::CameraPos,
+};
+use assert_assets_path::assert_assets_path;
+use bevy::{audio::PlaybackMode, prelude::*};
+use seldom_pixel::{
+    prelude::{PxAnchor, PxAssets, PxCanvas, PxSubPosition},
+    sprite::{PxSprite, PxSpriteBundle},
+};
+
+pub fn pickup_bomb(
+    mut commands: Commands,
+    query: Query<(Entity, &BombSupply, &PxSubPosition), Added<Dead>>,
+    camera_query: Query<&PxSubPosition, With<CameraPos>>,
+    mut player_query: Query<&mut BombStock, With<Player>>,
+    mut assets_sprite: PxAssets<PxSprite>,
+    asset_server: Res<AssetServer>,
+    volume_settings: Res<VolumeSettings>,
+) {
+    let camera_pos = camera_query.get_single().unwrap();
+    if let Ok(mut stock) = player_query.get_single_mut() {
+        for (entity, supply, position) in query.iter() {
+            commands.entity(entity).insert(DespawnMark);
+
+            stock.0 = stock.0.saturating_add(supply.0).min(PLAYER_MAX_BOMBS);
+
+            commands.spawn((
+                AudioBundle {
+                    source: asset_server.load(assert_assets_path!("audio/sfx/bomb_pickup.ogg")),
+                    settings: PlaybackSettings {
+                        mode: PlaybackMode::Despawn,
+                        volume: volume_settings.sfx.clone(),
+                        ..default()
+                    },
+                    ..default()
+                },
+                AudioSystemBundle {
+                    system_type: AudioSystemType::SFX,
+                },
+            ));
+
+            let current = position.0 - camera_pos.0;
+            let sprite = assets_sprite.load(assert_assets_path!("sprites/pickups/bomb_4.png"));
+
+            commands.spawn(PickupFeedbackBundle {
+                position: current.into(),
+                sprite: PxSpriteBundle::<Layer> {
+                    sprite,
+                    anchor: PxAnchor::Center,
+                    canvas: PxCanvas::Camera,
+                    layer: Layer::Pickups,
+                    ..default()
+                },
+                movement: PickupFeedbackMovementBundle::new_towards(
+                    current,
+                    PICKUP_FEEDBACK_BOMB_TARGET,
+                ),
+                default: PickupFeedbackDefaultBundle {
+                    name: Name::new("Pickup Bomb Feedback"),
+                    pickup_feedback: PickupFeedback,
+                },
+            });
+        }
+    }
+}
@@ -9,7 +9,8 @@ use crate::{
     stage::{
         components::interactive::{Dead, Health},
         pickup::components::{
-            HealthRecovery, PickupFeedback, PICKUP_FEEDBACK_INITIAL_SPEED_Y, PICKUP_FEEDBACK_TIME,
+            HealthRecovery, PickupFeedback, PICKUP_FEEDBACK_HEALTH_TARGET,
+            PICKUP_FEEDBACK_INITIAL_SPEED_Y, PICKUP_FEEDBACK_TIME,
         },
         player::components::{Player, PLAYER_MAX_HEALTH},
         resources::StageTime,
@@ -39,9 +40,15 @@ pub struct PickupFeedbackMovementBundle {
 
 impl PickupFeedbackMovementBundle {
     pub fn new(current: Vec2) -> Self {
+        Self::new_towards(current, PICKUP_FEEDBACK_HEALTH_TARGET)
+    }
+
+    /**
+     * Arcs from the current position into the target, usually its HUD counter
+     */
+    pub fn new_towards(current: Vec2, target: Vec2) -> Self {
         let t = PICKUP_FEEDBACK_TIME;
 
-        let target = Vec2::new(12., 8.);
         let d = target - current;
 
         let speed_x = d.x / t;
@@ -78,8 +85,8 @@ impl PickupFeedbackMovementBundle {
 
 #[derive(Bundle)]
 pub struct PickupFeedbackDefaultBundle {
-    name: Name,
-    pickup_feedback: PickupFeedback,
+    pub name: Name,
+    pub pickup_feedback: PickupFeedback,
 }
 
 impl Default for PickupFeedbackDefaultBundle {
@@ -93,10 +100,10 @@ impl Default for PickupFeedbackDefaultBundle {
 
 #[derive(Bundle)]
 pub struct PickupFeedbackBundle {
-    position: PxSubPosition,
-    sprite: PxSpriteBundle<Layer>,
-    movement: PickupFeedbackMovementBundle,
-    default: PickupFeedbackDefaultBundle,
+    pub position: PxSubPosition,
+    pub sprite: PxSpriteBundle<Layer>,
+    pub movement: PickupFeedbackMovementBundle,
+    pub default: PickupFeedbackDefaultBundle,
 }
 
 pub fn pickup_health(
@@ -0,0 +1,61 @@
+use crate::{
+    components::DespawnMark,
+    stage::{
+        components::{
+            interactive::Dead,
+            placement::{Depth, Floor, InView},
+        },
+        pickup::components::{PickupFall, PickupLifetime, PICKUP_DRIFT_DAMPING, PICKUP_GRAVITY},
+        resources::StageTime,
+    },
+};
+use bevy::prelude::*;
+use seldom_pixel::prelude::PxSubPosition;
+
+pub fn update_pickup_lifetime(
+    mut commands: Commands,
+    stage_time: Res<StageTime>,
+    mut query: Query<(Entity, &mut PickupLifetime, &mut Visibility), (With<InView>, Without<Dead>)>,
+) {
+    for (entity, mut lifetime, mut visibility) in query.iter_mut() {
+        lifetime.elapsed += stage_time.delta;
+
+        if lifetime.is_expired() {
+            commands.entity(entity).insert(DespawnMark);
+        } else if lifetime.is_warning() {
+            *visibility = if lifetime.is_blink_visible() {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+        }
+    }
+}
+
+pub fn update_pickup_fall(
+    mut commands: Commands,
+    stage_time: Res<StageTime>,
+    floor_query: Query<(&Floor, &Depth)>,
+    mut query: Query<(Entity, &mut PickupFall, &mut PxSubPosition, &Depth), Without<Dead>>,
+) {
+    let delta = stage_time.delta.as_secs_f32();
+    for (entity, mut fall, mut position, depth) in query.iter_mut() {
+        let floor = floor_query
+            .iter()
+            .find(|(_, floor_depth)| *floor_depth == depth)
+            .map(|(floor, _)| floor.0);
+
+        if let Some(floor) = floor {
+            fall.velocity.y -= PICKUP_GRAVITY * delta;
+            fall.velocity.x *= (1. - PICKUP_DRIFT_DAMPING * delta).max(0.);
+            position.0 += fall.velocity * delta;
+
+            if position.0.y <= floor && fall.velocity.y <= 0. {
+                position.0.y = floor;
+                commands.entity(entity).remove::<PickupFall>();
+            }
+        } else {
+            commands.entity(entity).remove::<PickupFall>();
+        }
+    }
+}
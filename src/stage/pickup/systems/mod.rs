@@ -1 +1,3 @@
+pub mod bomb;
 pub mod health;
+pub mod lifetime;
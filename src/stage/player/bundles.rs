@@ -1,4 +1,8 @@
-use super::{components::*, crosshair::CrosshairSettings, CrosshairInfo};
+use super::{
+    components::*,
+    crosshair::{Crosshair, CrosshairSettings},
+    CrosshairInfo,
+};
 use crate::{
     globals::*,
     layer::Layer,
@@ -13,16 +17,22 @@ pub fn make_player_bundle(
 ) -> (
     Name,
     Player,
+    Crosshair,
+    PlayerVelocity,
+    BombStock,
     Health,
     PxSpriteBundle<Layer>,
     PxSubPosition,
     StageEntity,
 ) {
     let crosshair_info = CrosshairInfo::crosshair_sprite(asset_server, crosshair_settings);
-    let sprite = CrosshairInfo::get_sprite(crosshair_info);
+    let CrosshairInfo { sprite, crosshair } = crosshair_info;
     (
         Name::new("Player"),
         Player,
+        crosshair,
+        PlayerVelocity::default(),
+        BombStock(PLAYER_STARTING_BOMBS),
         Health(PLAYER_MAX_HEALTH),
         PxSpriteBundle::<Layer> {
             canvas: PxCanvas::Camera,
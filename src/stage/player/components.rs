@@ -1,4 +1,4 @@
-use crate::components::{AudioSystemBundle, AudioSystemType, VolumeSettings};
+use crate::components::{AudioSystemBundle, AudioSystemType, GBColor, VolumeSettings};
 use crate::{layer::Layer, stage::components::placement::Depth};
 use assert_assets_path::assert_assets_path;
 use bevy::{audio::PlaybackMode, prelude::*, utils::HashSet};
@@ -9,11 +9,30 @@ use seldom_pixel::{
     },
     sprite::{PxSprite, PxSpriteBundle},
 };
+use std::time::Duration;
 
 #[derive(Component)]
 pub struct Player;
 
+#[derive(Clone, Component, Copy, Debug, Default, Reflect)]
+pub struct PlayerVelocity(pub Vec2);
+
+#[derive(Clone, Component, Copy, Debug, Default, Reflect)]
+pub struct BombStock(pub u8);
+
+#[derive(Clone, Component, Debug, Reflect)]
+pub struct BombFlash {
+    pub started: Duration,
+}
+
 pub const PLAYER_SPEED: f32 = 125.;
+pub const PLAYER_ACCELERATION: f32 = 900.;
+pub const PLAYER_DECELERATION: f32 = 1400.;
+
+/** How far from a collider's edge the aim assist kicks in */
+pub const AIM_ASSIST_RANGE: f32 = 6.;
+pub const AIM_ASSIST_SLOWDOWN: f32 = 0.6;
+pub const AIM_ASSIST_PULL: f32 = 3.;
 pub const PLAYER_SIZE: f32 = 0.;
 pub const PLAYER_MAX_HEALTH: u32 = 100;
 pub const PLAYER_DEPTH: Depth = Depth::Zero;
@@ -21,6 +40,19 @@ pub const PLAYER_DEPTH: Depth = Depth::Zero;
 pub const ATTACK_PINCER_DAMAGE: u32 = 70;
 pub const ATTACK_GUN_DAMAGE: u32 = 30;
 
+pub const PLAYER_STARTING_BOMBS: u8 = 1;
+pub const PLAYER_MAX_BOMBS: u8 = 9;
+pub const BOMB_DAMAGE: u32 = 100;
+/** Palette the screen cycles through once a bomb goes off */
+pub const BOMB_FLASH_COLORS: [GBColor; 5] = [
+    GBColor::White,
+    GBColor::LightGray,
+    GBColor::White,
+    GBColor::DarkGray,
+    GBColor::White,
+];
+pub const BOMB_FLASH_PHASE_DURATION: Duration = Duration::from_millis(60);
+
 #[derive(Clone, Copy, Debug, Reflect)]
 pub enum Weapon {
     Pincer,
@@ -1,15 +1,35 @@
+use crate::{core::save::write_save, globals::DEFAULT_CROSSHAIR_INDEX};
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub const CROSSHAIR_SETTINGS_FILE: &str = "crosshair.ron";
+/** Amount of crosshair styles handled by CrosshairInfo::crosshair_sprite */
+pub const CROSSHAIR_COUNT: u8 = 3;
 
 #[derive(Component)]
 pub struct Crosshair {
     pub name: String,
 }
 
-#[derive(Debug, Clone, Copy, Resource)]
+#[derive(Debug, Clone, Copy, Deserialize, Resource, Serialize)]
 pub struct CrosshairSettings(pub u8);
 
 impl Default for CrosshairSettings {
     fn default() -> Self {
-        CrosshairSettings(1)
+        CrosshairSettings(DEFAULT_CROSSHAIR_INDEX)
+    }
+}
+
+impl CrosshairSettings {
+    pub fn next(&self) -> Self {
+        CrosshairSettings((self.0 + 1) % CROSSHAIR_COUNT)
+    }
+
+    pub fn previous(&self) -> Self {
+        CrosshairSettings((self.0 + CROSSHAIR_COUNT - 1) % CROSSHAIR_COUNT)
+    }
+
+    pub fn save(&self) {
+        write_save(CROSSHAIR_SETTINGS_FILE, self);
     }
 }
@@ -9,3 +9,6 @@ pub struct PlayerStartupTrigger;
 
 #[derive(Event)]
 pub struct PlayerShutdownTrigger;
+
+#[derive(Event)]
+pub struct BombDetonationTrigger;
@@ -8,14 +8,16 @@ mod systems;
 use self::{
     crosshair::{Crosshair, CrosshairSettings},
     events::*,
-    resources::AttackTimer,
+    resources::{AimSettings, AttackTimer},
     systems::{
+        bomb::{detect_bomb_detonation, on_bomb_detonation, update_bomb_flash},
         camera::{camera_shake, on_camera_shake},
         events::*,
         *,
     },
 };
 use super::resources::StageTime;
+use crate::game::GameProgressState;
 use assert_assets_path::assert_assets_path;
 use bevy::prelude::*;
 use seldom_pixel::{prelude::PxAssets, sprite::PxSprite};
@@ -31,10 +33,18 @@ pub struct PlayerPlugin;
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<AttackTimer>()
+            .init_resource::<AimSettings>()
+            .add_systems(Startup, (load_aim_settings, load_crosshair_settings))
+            .add_systems(
+                Update,
+                update_crosshair.run_if(resource_changed::<CrosshairSettings>),
+            )
             .init_state::<PlayerPluginUpdateState>()
             .configure_sets(Update, MovementSystemSet.before(ConfinementSystemSet))
             .add_event::<CameraShakeTrigger>()
             .observe(on_camera_shake)
+            .add_event::<BombDetonationTrigger>()
+            .observe(on_bomb_detonation)
             .add_event::<PlayerStartupTrigger>()
             .observe(on_player_startup)
             .add_event::<PlayerShutdownTrigger>()
@@ -44,11 +54,16 @@ impl Plugin for PlayerPlugin {
                 (
                     tick_attack_timer::<StageTime>,
                     check_attack_timer,
-                    detect_player_attack,
+                    // After movement, so that taps attack where they land
+                    detect_player_attack.after(ConfinementSystemSet),
+                    detect_bomb_detonation,
+                    update_bomb_flash::<StageTime>,
                     camera_shake::<StageTime>,
                     player_movement::<StageTime>.in_set(MovementSystemSet),
+                    pointer_movement.in_set(MovementSystemSet),
                     confine_player_movement.in_set(ConfinementSystemSet),
                 )
+                    .run_if(not(in_state(GameProgressState::Paused)))
                     .run_if(in_state(PlayerPluginUpdateState::Active)),
             );
     }
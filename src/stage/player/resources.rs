@@ -1,4 +1,8 @@
+use crate::core::save::write_save;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub const AIM_SETTINGS_FILE: &str = "aim_settings.ron";
 
 #[derive(Resource)]
 pub struct AttackTimer {
@@ -12,3 +16,50 @@ impl Default for AttackTimer {
         AttackTimer { timer }
     }
 }
+
+#[derive(Clone, Debug, Deserialize, Resource, Serialize)]
+#[serde(default)]
+pub struct AimSettings {
+    /**
+     * The crosshair follows the mouse cursor or touch point, and clicks or taps attack
+     */
+    pub pointer: bool,
+    /**
+     * Analog stick magnitude below which its input is ignored, between 0 and 1
+     */
+    pub deadzone: f32,
+    /**
+     * Slows down and pulls the crosshair towards nearby targets, never applied on Hard
+     */
+    pub aim_assist: bool,
+}
+
+impl Default for AimSettings {
+    fn default() -> Self {
+        Self {
+            // The web build is mostly played with a mouse or on touch screens
+            pointer: cfg!(target_arch = "wasm32"),
+            deadzone: 0.2,
+            aim_assist: true,
+        }
+    }
+}
+
+impl AimSettings {
+    /**
+     * Rescales the stick input so that it starts from zero right outside of the deadzone
+     */
+    pub fn apply_deadzone(&self, stick: Vec2) -> Vec2 {
+        let magnitude = stick.length();
+        if magnitude <= self.deadzone {
+            Vec2::ZERO
+        } else {
+            let rescaled = ((magnitude - self.deadzone) / (1. - self.deadzone)).min(1.);
+            stick / magnitude * rescaled
+        }
+    }
+
+    pub fn save(&self) {
+        write_save(AIM_SETTINGS_FILE, self);
+    }
+}
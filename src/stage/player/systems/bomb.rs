@@ -0,0 +1,140 @@
+use crate::{
+    components::{AudioSystemBundle, AudioSystemType, DespawnMark, GBColor, VolumeSettings},
+    core::time::ElapsedTime,
+    globals::SCREEN_RESOLUTION,
+    input::GBInput,
+    layer::Layer,
+    pixel::components::PxRectangle,
+    stage::{
+        attack::components::EnemyAttack,
+        components::{
+            interactive::{Dead, Hittable},
+            placement::InView,
+        },
+        events::DamageEvent,
+        pickup::components::{BombSupply, HealthRecovery},
+        player::{
+            components::{
+                BombFlash, BombStock, Player, BOMB_DAMAGE, BOMB_FLASH_COLORS,
+                BOMB_FLASH_PHASE_DURATION,
+            },
+            events::{BombDetonationTrigger, CameraShakeTrigger},
+        },
+        resources::StageTime,
+    },
+};
+use assert_assets_path::assert_assets_path;
+use bevy::{audio::PlaybackMode, prelude::*};
+use leafwing_input_manager::prelude::ActionState;
+use seldom_pixel::prelude::{PxAnchor, PxCanvas, PxSubPosition};
+
+/**
+ * A and B held together, as soon as the second one goes down
+ */
+pub fn is_bomb_combo(gb_input: &ActionState<GBInput>) -> bool {
+    gb_input.pressed(&GBInput::A)
+        && gb_input.pressed(&GBInput::B)
+        && (gb_input.just_pressed(&GBInput::A) || gb_input.just_pressed(&GBInput::B))
+}
+
+pub fn detect_bomb_detonation(mut commands: Commands, gb_input: Res<ActionState<GBInput>>) {
+    if is_bomb_combo(&gb_input) {
+        commands.trigger(BombDetonationTrigger);
+    }
+}
+
+pub fn on_bomb_detonation(
+    _trigger: Trigger<BombDetonationTrigger>,
+    mut commands: Commands,
+    mut event_writer: EventWriter<DamageEvent>,
+    mut player_query: Query<&mut BombStock, With<Player>>,
+    hittable_query: Query<
+        Entity,
+        (
+            With<Hittable>,
+            With<InView>,
+            Without<Dead>,
+            Without<EnemyAttack>,
+            Without<HealthRecovery>,
+            Without<BombSupply>,
+        ),
+    >,
+    attack_query: Query<Entity, With<EnemyAttack>>,
+    asset_server: Res<AssetServer>,
+    volume_settings: Res<VolumeSettings>,
+    stage_time: Res<StageTime>,
+) {
+    let Ok(mut stock) = player_query.get_single_mut() else {
+        return;
+    };
+    if stock.0 == 0 {
+        // TODO little sound indicating there are no bombs left
+        return;
+    }
+    stock.0 -= 1;
+
+    for entity in hittable_query.iter() {
+        event_writer.send(DamageEvent::new(entity, BOMB_DAMAGE));
+    }
+
+    for entity in attack_query.iter() {
+        commands.entity(entity).insert(DespawnMark);
+    }
+
+    commands.spawn((
+        AudioBundle {
+            source: asset_server.load(assert_assets_path!("audio/sfx/bomb_explode.ogg")),
+            settings: PlaybackSettings {
+                mode: PlaybackMode::Despawn,
+                volume: volume_settings.sfx.clone(),
+                ..default()
+            },
+            ..default()
+        },
+        AudioSystemBundle {
+            system_type: AudioSystemType::SFX,
+        },
+    ));
+
+    commands.spawn((
+        Name::new("BombFlash"),
+        BombFlash {
+            started: stage_time.elapsed,
+        },
+        PxSubPosition(Vec2::ZERO),
+        PxRectangle {
+            anchor: PxAnchor::BottomLeft,
+            canvas: PxCanvas::Camera,
+            color: BOMB_FLASH_COLORS[0],
+            width: SCREEN_RESOLUTION.x,
+            height: SCREEN_RESOLUTION.y,
+            layer: Layer::Front,
+        },
+    ));
+
+    commands.trigger(CameraShakeTrigger);
+}
+
+/**
+ * Steps the flash through its palette, then removes it
+ */
+pub fn update_bomb_flash<T: ElapsedTime + Resource>(
+    mut commands: Commands,
+    mut query: Query<(Entity, &BombFlash, &mut GBColor)>,
+    time: Res<T>,
+) {
+    for (entity, flash, mut color) in query.iter_mut() {
+        let phase = ((time.elapsed() - flash.started).as_secs_f32()
+            / BOMB_FLASH_PHASE_DURATION.as_secs_f32()) as usize;
+        match BOMB_FLASH_COLORS.get(phase) {
+            Some(next) => {
+                if *color != *next {
+                    *color = *next;
+                }
+            }
+            None => {
+                commands.entity(entity).insert(DespawnMark);
+            }
+        }
+    }
+}
@@ -1,15 +1,30 @@
+pub mod bomb;
 pub mod camera;
 pub mod events;
 
+use self::bomb::is_bomb_combo;
 use super::components::*;
-use super::resources::AttackTimer;
-use crate::core::time::DeltaTime;
+use super::{
+    crosshair::{Crosshair, CrosshairSettings, CROSSHAIR_SETTINGS_FILE},
+    resources::{AimSettings, AttackTimer, AIM_SETTINGS_FILE},
+    CrosshairInfo,
+};
+use crate::core::{save::load_save, time::DeltaTime};
 use crate::input::GBInput;
 use crate::{
     components::{DespawnMark, VolumeSettings},
-    globals::{mark_for_despawn_by_query, HUD_HEIGHT, SCREEN_RESOLUTION},
+    game::resources::Difficulty,
+    globals::{
+        mark_for_despawn_by_query, window_to_screen_position, HUD_HEIGHT, SCREEN_RESOLUTION,
+    },
+    resources::DifficultySelected,
+    stage::components::{
+        interactive::{ColliderData, ColliderShape, Hittable},
+        placement::InView,
+    },
+    systems::camera::CameraPos,
 };
-use bevy::prelude::*;
+use bevy::{prelude::*, window::PrimaryWindow};
 use leafwing_input_manager::prelude::ActionState;
 use seldom_pixel::prelude::*;
 use std::time::Duration;
@@ -40,25 +55,172 @@ pub fn confine_player_movement(mut player_query: Query<&mut PxSubPosition, With<
     }
 }
 
+/**
+ * Eases the crosshair velocity towards the one requested by the directional or analog input
+ */
 pub fn player_movement<T: DeltaTime + Resource>(
     gb_input: Res<ActionState<GBInput>>,
+    aim_settings: Res<AimSettings>,
+    difficulty: Res<DifficultySelected>,
+    gamepads: Res<Gamepads>,
+    axes: Res<Axis<GamepadAxis>>,
+    camera_query: Query<&PxSubPosition, (With<CameraPos>, Without<Player>)>,
+    hittable_query: Query<
+        (&PxSubPosition, &ColliderData),
+        (With<Hittable>, With<InView>, Without<Player>),
+    >,
     // TODO should this system refer to a Cursor component instead?
-    mut query: Query<&mut PxSubPosition, With<Player>>,
+    mut query: Query<(&mut PxSubPosition, &mut PlayerVelocity), With<Player>>,
     time: Res<T>,
 ) {
-    for mut position in &mut query {
-        // TODO review what's more expensive, querying or input subroutine, although, most of the times
-        // a player will exist, so it's probably more that the former is redundant
-        let mut direction = Vec2::new(
-            (gb_input.pressed(&GBInput::Right) as i32 - gb_input.pressed(&GBInput::Left) as i32)
-                as f32,
-            (gb_input.pressed(&GBInput::Up) as i32 - gb_input.pressed(&GBInput::Down) as i32)
-                as f32,
-        );
-
-        if direction.length() > 0.0 {
-            direction = direction.normalize_or_zero();
-            position.0 += direction * PLAYER_SPEED * time.delta().as_secs_f32();
+    let delta = time.delta().as_secs_f32();
+    let direction = Vec2::new(
+        (gb_input.pressed(&GBInput::Right) as i32 - gb_input.pressed(&GBInput::Left) as i32) as f32,
+        (gb_input.pressed(&GBInput::Up) as i32 - gb_input.pressed(&GBInput::Down) as i32) as f32,
+    )
+    .normalize_or_zero();
+    let stick = aim_settings.apply_deadzone(read_left_stick(&gamepads, &axes));
+    let input = if stick.length() > direction.length() {
+        stick
+    } else {
+        direction
+    };
+
+    let aim_assist = aim_settings.aim_assist && difficulty.0 != Difficulty::Hard;
+    let camera_pos = camera_query.get_single().map_or(Vec2::ZERO, |x| x.0);
+
+    for (mut position, mut velocity) in &mut query {
+        let target = if aim_assist && input != Vec2::ZERO {
+            find_aim_assist_target(position.0 + camera_pos, &hittable_query).map(|x| x - camera_pos)
+        } else {
+            None
+        };
+
+        let max_speed = PLAYER_SPEED * target.map_or(1., |_| AIM_ASSIST_SLOWDOWN);
+        let target_velocity = input * max_speed;
+        let rate = if target_velocity.length() > velocity.0.length() {
+            PLAYER_ACCELERATION
+        } else {
+            PLAYER_DECELERATION
+        };
+        velocity.0 = move_towards(velocity.0, target_velocity, rate * delta);
+        position.0 += velocity.0 * delta;
+
+        if let Some(target) = target {
+            position.0 += (target - position.0) * (AIM_ASSIST_PULL * delta).min(1.);
+        }
+    }
+}
+
+/**
+ * Closest collider centre, in world coordinates, that is within reach of the assist
+ */
+fn find_aim_assist_target(
+    world_position: Vec2,
+    hittable_query: &Query<
+        (&PxSubPosition, &ColliderData),
+        (With<Hittable>, With<InView>, Without<Player>),
+    >,
+) -> Option<Vec2> {
+    hittable_query
+        .iter()
+        .flat_map(|(position, collider_data)| {
+            collider_data.0.iter().map(move |collider| {
+                let size = match collider.shape {
+                    ColliderShape::Box(size) => size.min_element(),
+                    ColliderShape::Circle(radius) => radius,
+                };
+                (position.0 + collider.offset, size)
+            })
+        })
+        .filter(|(centre, size)| centre.distance(world_position) <= size + AIM_ASSIST_RANGE)
+        .map(|(centre, _)| centre)
+        .min_by(|a, b| {
+            a.distance(world_position)
+                .total_cmp(&b.distance(world_position))
+        })
+}
+
+fn read_left_stick(gamepads: &Gamepads, axes: &Axis<GamepadAxis>) -> Vec2 {
+    gamepads
+        .iter()
+        .map(|gamepad| {
+            Vec2::new(
+                axes.get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX))
+                    .unwrap_or(0.),
+                axes.get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickY))
+                    .unwrap_or(0.),
+            )
+        })
+        .max_by(|a, b| a.length().total_cmp(&b.length()))
+        .unwrap_or(Vec2::ZERO)
+}
+
+fn move_towards(current: Vec2, target: Vec2, max_delta: f32) -> Vec2 {
+    let difference = target - current;
+    let distance = difference.length();
+    if distance <= max_delta {
+        target
+    } else {
+        current + difference / distance * max_delta
+    }
+}
+
+pub fn load_aim_settings(mut commands: Commands) {
+    commands.insert_resource(load_save::<AimSettings>(AIM_SETTINGS_FILE).unwrap_or_default());
+}
+
+pub fn load_crosshair_settings(mut commands: Commands) {
+    commands.insert_resource(
+        load_save::<CrosshairSettings>(CROSSHAIR_SETTINGS_FILE).unwrap_or_default(),
+    );
+}
+
+/**
+ * Swaps the sprite of every crosshair, both the player's and any menu preview
+ */
+pub fn update_crosshair(
+    mut assets_sprite: PxAssets<PxSprite>,
+    crosshair_settings: Res<CrosshairSettings>,
+    mut query: Query<(&mut Handle<PxSprite>, &mut Crosshair)>,
+) {
+    for (mut sprite, mut crosshair) in &mut query {
+        let crosshair_info =
+            CrosshairInfo::crosshair_sprite(&mut assets_sprite, &crosshair_settings);
+        *sprite = crosshair_info.sprite;
+        *crosshair = crosshair_info.crosshair;
+    }
+}
+
+/**
+ * Moves the crosshair to the mouse cursor, when it moves, or to the current touch point
+ */
+pub fn pointer_movement(
+    mut cursor_moved_reader: EventReader<CursorMoved>,
+    aim_settings: Res<AimSettings>,
+    touches: Res<Touches>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut query: Query<&mut PxSubPosition, With<Player>>,
+) {
+    let cursor_position = cursor_moved_reader.read().last().map(|e| e.position);
+    if !aim_settings.pointer {
+        return;
+    }
+
+    let Ok(window) = window_query.get_single() else {
+        return;
+    };
+
+    let pointer_position = touches
+        .iter()
+        .next()
+        .map(|touch| touch.position())
+        .or(cursor_position)
+        .and_then(|x| window_to_screen_position(window.size(), x));
+
+    if let Some(pointer_position) = pointer_position {
+        for mut position in &mut query {
+            position.0 = pointer_position;
         }
     }
 }
@@ -69,15 +231,25 @@ pub fn detect_player_attack(
     mut timer: ResMut<AttackTimer>,
     asset_server: Res<AssetServer>,
     gb_input: Res<ActionState<GBInput>>,
+    aim_settings: Res<AimSettings>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
     player_attack_query: Query<&PlayerAttack>,
     player_query: Query<&PxSubPosition, With<Player>>,
     volume_settings: Res<VolumeSettings>,
 ) {
     if player_attack_query.iter().next().is_none() {
         if let Ok(position) = player_query.get_single() {
-            let attack = if gb_input.just_pressed(&GBInput::A) {
+            let pointer_melee =
+                aim_settings.pointer && mouse_buttons.just_pressed(MouseButton::Right);
+            let pointer_shot = aim_settings.pointer
+                && (mouse_buttons.just_pressed(MouseButton::Left) || touches.any_just_pressed());
+
+            let attack = if is_bomb_combo(&gb_input) {
+                None
+            } else if gb_input.just_pressed(&GBInput::A) || pointer_melee {
                 Some((Weapon::Pincer, 0.6))
-            } else if gb_input.just_pressed(&GBInput::B) {
+            } else if gb_input.just_pressed(&GBInput::B) || pointer_shot {
                 Some((Weapon::Gun, 0.08))
             } else {
                 None
@@ -1,11 +1,13 @@
-use std::time::Duration;
+use std::{collections::BTreeMap, time::Duration};
 
 use bevy::prelude::*;
 use derive_new::new;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DurationSecondsWithFrac};
 
 use crate::core::time::*;
 
-use super::data::StageSpawn;
+use super::{data::StageSpawn, enemy::entity::EnemyType, player::components::PLAYER_MAX_HEALTH};
 
 #[derive(Resource, Default, Debug, Clone, Copy, Reflect)]
 pub struct StageTime {
@@ -58,3 +60,150 @@ pub struct StageStepSpawner {
     pub elapsed_since_spawn: Duration,
     pub spawns: Vec<StageSpawn>,
 }
+
+pub const STAGE_RECORDS_FILE: &str = "stage_records.ron";
+
+pub const GRADE_ACCURACY_WEIGHT: f32 = 0.4;
+pub const GRADE_CRITICAL_WEIGHT: f32 = 0.2;
+pub const GRADE_HEALTH_WEIGHT: f32 = 0.4;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum StageGrade {
+    D,
+    C,
+    B,
+    A,
+    S,
+}
+
+impl StageGrade {
+    pub fn from_rating(rating: f32) -> Self {
+        if rating >= 0.9 {
+            StageGrade::S
+        } else if rating >= 0.75 {
+            StageGrade::A
+        } else if rating >= 0.6 {
+            StageGrade::B
+        } else if rating >= 0.4 {
+            StageGrade::C
+        } else {
+            StageGrade::D
+        }
+    }
+
+    pub fn bonus_score(&self) -> u32 {
+        match self {
+            StageGrade::S => 1000,
+            StageGrade::A => 500,
+            StageGrade::B => 250,
+            StageGrade::C => 100,
+            StageGrade::D => 0,
+        }
+    }
+
+    pub fn show(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/**
+ * Statistics tracked while a stage is being played, reset on every stage startup
+ */
+#[serde_as]
+#[derive(Resource, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct StageStatistics {
+    #[serde(skip)]
+    pub started: Duration,
+    #[serde_as(as = "DurationSecondsWithFrac")]
+    pub time_taken: Duration,
+    pub kills: BTreeMap<EnemyType, u32>,
+    pub attacks_fired: u32,
+    pub attacks_hit: u32,
+    pub criticals: u32,
+    pub damage_taken: u32,
+    pub pickups_collected: u32,
+}
+
+impl StageStatistics {
+    pub fn new(started: Duration) -> Self {
+        Self {
+            started,
+            ..default()
+        }
+    }
+
+    pub fn add_kill(&mut self, enemy_type: EnemyType) {
+        *self.kills.entry(enemy_type).or_insert(0) += 1;
+    }
+
+    pub fn total_kills(&self) -> u32 {
+        self.kills.values().sum()
+    }
+
+    pub fn accuracy(&self) -> f32 {
+        if self.attacks_fired == 0 {
+            0.
+        } else {
+            (self.attacks_hit as f32 / self.attacks_fired as f32).min(1.)
+        }
+    }
+
+    pub fn critical_ratio(&self) -> f32 {
+        if self.attacks_hit == 0 {
+            0.
+        } else {
+            (self.criticals as f32 / self.attacks_hit as f32).min(1.)
+        }
+    }
+
+    pub fn health_ratio(&self) -> f32 {
+        1. - (self.damage_taken as f32 / PLAYER_MAX_HEALTH as f32).min(1.)
+    }
+
+    pub fn grade(&self) -> StageGrade {
+        StageGrade::from_rating(
+            self.accuracy() * GRADE_ACCURACY_WEIGHT
+                + self.critical_ratio() * GRADE_CRITICAL_WEIGHT
+                + self.health_ratio() * GRADE_HEALTH_WEIGHT,
+        )
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StageRecord {
+    pub grade: StageGrade,
+    pub statistics: StageStatistics,
+}
+
+/**
+ * Best results per stage, keyed by the stage name
+ */
+#[derive(Resource, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct StageRecords {
+    pub stages: BTreeMap<String, StageRecord>,
+}
+
+impl StageRecords {
+    /**
+     * Returns true if the statistics were stored as the new best record for the stage
+     */
+    pub fn record(&mut self, name: &str, statistics: &StageStatistics) -> bool {
+        let grade = statistics.grade();
+        let is_best = self
+            .stages
+            .get(name)
+            .map(|record| grade > record.grade)
+            .unwrap_or(true);
+
+        if is_best {
+            self.stages.insert(
+                name.to_string(),
+                StageRecord {
+                    grade,
+                    statistics: statistics.clone(),
+                },
+            );
+        }
+        is_best
+    }
+}
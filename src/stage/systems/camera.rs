@@ -1,7 +1,14 @@
 use crate::{
     globals::{is_inside_area, HUD_HEIGHT, SCREEN_RESOLUTION},
     plugins::movement::linear::components::{TargetingPositionX, TargetingPositionY},
-    stage::{components::placement::InView, player::components::CameraShake},
+    stage::{
+        components::{
+            movement::CameraPathMovement,
+            placement::{InView, Parallax},
+        },
+        player::components::CameraShake,
+        resources::StageTime,
+    },
     systems::camera::CameraPos,
 };
 use bevy::prelude::*;
@@ -50,6 +57,26 @@ pub fn check_outside_view(
     }
 }
 
+pub fn update_camera_path_movement(
+    stage_time: Res<StageTime>,
+    mut query: Query<
+        (
+            &CameraPathMovement,
+            &mut TargetingPositionX,
+            &mut TargetingPositionY,
+        ),
+        With<CameraPos>,
+    >,
+) {
+    if let Ok((movement, mut x, mut y)) = query.get_single_mut() {
+        let position = movement
+            .track
+            .position_at(stage_time.elapsed.saturating_sub(movement.started));
+        x.0 = position.x;
+        y.0 = position.y;
+    }
+}
+
 pub fn update_camera_pos_x(
     mut query: Query<
         (&TargetingPositionX, &mut PxSubPosition),
@@ -71,3 +98,19 @@ pub fn update_camera_pos_y(
         camera_pos.0.y = pos.0;
     }
 }
+
+pub fn update_parallax(
+    stage_time: Res<StageTime>,
+    camera_query: Query<&PxSubPosition, (With<CameraPos>, Without<Parallax>)>,
+    mut query: Query<(&mut Parallax, &mut PxSubPosition)>,
+) {
+    let Ok(camera_pos) = camera_query.get_single() else {
+        return;
+    };
+    let delta = stage_time.delta.as_secs_f32();
+    for (mut parallax, mut position) in query.iter_mut() {
+        let autoscroll = parallax.autoscroll;
+        parallax.scrolled += autoscroll * delta;
+        position.0 = parallax.position(camera_pos.0);
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{
+    components::Tag,
+    cutscene::{
+        components::SpriteTweens,
+        data::{CutsceneAnimationsSpawn, CutsceneLayer},
+    },
+    globals::{
+        mark_for_despawn_by_query, FONT_SIZE, SCREEN_RESOLUTION, TYPEFACE_CHARACTERS, TYPEFACE_PATH,
+    },
+    input::GBInput,
+    layer::Layer,
+    letterbox::{components::LETTERBOX_HEIGHT, events::LetterboxMoveTrigger},
+    stage::{
+        components::{
+            CinematicGraphic, CinematicProgress, CinematicStageStep, CinematicText,
+            CurrentStageStep, Stage, StageEntity,
+        },
+        events::NextStepEvent,
+        resources::StageTime,
+        StageGameplayState,
+    },
+};
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::ActionState;
+use seldom_pixel::{
+    prelude::{
+        PxAnchor, PxAnimationBundle, PxAnimationDuration, PxAnimationFinishBehavior, PxAssets,
+        PxCanvas, PxSubPosition, PxTextBundle, PxTypeface,
+    },
+    sprite::{PxSprite, PxSpriteBundle},
+};
+
+const DIALOGUE_MARGIN: i32 = 4;
+
+fn spawn_cinematic_animations(
+    commands: &mut Commands,
+    assets_sprite: &mut PxAssets<PxSprite>,
+    spawns: &CutsceneAnimationsSpawn,
+) {
+    for spawn in spawns.spawns.iter() {
+        let sprite = assets_sprite.load_animated(spawn.image_path.clone(), spawn.frame_count);
+
+        let mut entity_commands = commands.spawn((
+            Name::new("CinematicGraphic"),
+            CinematicGraphic,
+            StageEntity,
+            PxSpriteBundle::<Layer> {
+                sprite,
+                anchor: PxAnchor::BottomLeft,
+                canvas: PxCanvas::Camera,
+                layer: spawn.layer.clone(),
+                ..default()
+            },
+            PxAnimationBundle {
+                duration: PxAnimationDuration::millis_per_animation(
+                    spawn.duration.as_millis() as u64
+                ),
+                on_finish: PxAnimationFinishBehavior::Loop,
+                ..default()
+            },
+            PxSubPosition::from(spawn.coordinates),
+        ));
+
+        if let Some(tag) = &spawn.tag_o {
+            entity_commands.insert(Tag(tag.clone()));
+        }
+
+        // The stage movement plugins are held still during cinematics
+        if let Some(target_movement) = &spawn.target_movement_o {
+            target_movement.insert_tween::<StageTime>(&mut entity_commands, spawn.coordinates);
+        }
+
+        if !spawn.tweens.is_empty() {
+            entity_commands.insert(SpriteTweens::<StageTime>::new(spawn.tweens.clone()));
+        }
+    }
+}
+
+fn spawn_cinematic_text(
+    commands: &mut Commands,
+    typefaces: &mut PxAssets<PxTypeface>,
+    text: String,
+) {
+    let typeface = typefaces.load(TYPEFACE_PATH, TYPEFACE_CHARACTERS, [(' ', 4)]);
+
+    commands.spawn((
+        Name::new("CinematicText"),
+        CinematicText,
+        StageEntity,
+        PxTextBundle::<Layer> {
+            alignment: PxAnchor::BottomLeft,
+            canvas: PxCanvas::Camera,
+            layer: Layer::CutsceneLayer(CutsceneLayer::Text),
+            rect: IRect::new(
+                DIALOGUE_MARGIN,
+                DIALOGUE_MARGIN,
+                SCREEN_RESOLUTION.x as i32 - DIALOGUE_MARGIN,
+                (LETTERBOX_HEIGHT as i32 - DIALOGUE_MARGIN).max(FONT_SIZE as i32),
+            )
+            .into(),
+            text: text.into(),
+            typeface,
+            ..default()
+        },
+    ));
+}
+
+pub fn initialise_cinematic_step(
+    mut commands: Commands,
+    query: Query<(Entity, &CinematicStageStep), (With<Stage>, Added<CinematicStageStep>)>,
+    mut assets_sprite: PxAssets<PxSprite>,
+    mut typefaces: PxAssets<PxTypeface>,
+    mut gameplay_state: ResMut<NextState<StageGameplayState>>,
+    stage_time: Res<StageTime>,
+) {
+    if let Ok((entity, step)) = query.get_single() {
+        let scene = step.to_scene();
+
+        gameplay_state.set(StageGameplayState::Cinematic);
+        commands.trigger(LetterboxMoveTrigger::open());
+
+        if let Some(animations) = &scene.animations {
+            spawn_cinematic_animations(&mut commands, &mut assets_sprite, animations);
+        }
+
+        let progress = CinematicProgress {
+            scene,
+            started: stage_time.elapsed,
+            line_index: 0,
+            line_started: stage_time.elapsed,
+        };
+
+        if let Some(line) = progress.current_line() {
+            spawn_cinematic_text(&mut commands, &mut typefaces, line);
+        }
+
+        commands.entity(entity).insert(progress);
+    }
+}
+
+pub fn update_cinematic_step(
+    mut commands: Commands,
+    mut query: Query<&mut CinematicProgress, With<Stage>>,
+    text_query: Query<Entity, With<CinematicText>>,
+    mut typefaces: PxAssets<PxTypeface>,
+    gb_input: Res<ActionState<GBInput>>,
+    stage_time: Res<StageTime>,
+) {
+    for mut progress in query.iter_mut() {
+        if let Some(dialogue) = progress.scene.dialogue.clone() {
+            if progress.current_line().is_some()
+                && (gb_input.just_pressed(&GBInput::A)
+                    || progress.line_started + dialogue.line_duration <= stage_time.elapsed)
+            {
+                progress.line_index += 1;
+                progress.line_started = stage_time.elapsed;
+
+                mark_for_despawn_by_query(&mut commands, &text_query);
+                if let Some(line) = progress.current_line() {
+                    spawn_cinematic_text(&mut commands, &mut typefaces, line);
+                }
+            }
+        }
+
+        if progress.is_finished(stage_time.elapsed) {
+            commands.trigger(NextStepEvent);
+        }
+    }
+}
+
+pub fn on_next_step_cleanup_cinematic_step(
+    _trigger: Trigger<NextStepEvent>,
+    mut commands: Commands,
+    query: Query<Entity, (With<Stage>, With<CinematicStageStep>)>,
+    graphic_query: Query<Entity, Or<(With<CinematicGraphic>, With<CinematicText>)>>,
+    mut gameplay_state: ResMut<NextState<StageGameplayState>>,
+) {
+    for entity in query.iter() {
+        commands
+            .entity(entity)
+            .remove::<CinematicStageStep>()
+            .remove::<CinematicProgress>()
+            .remove::<CurrentStageStep>();
+
+        mark_for_despawn_by_query(&mut commands, &graphic_query);
+        commands.trigger(LetterboxMoveTrigger::close());
+        gameplay_state.set(StageGameplayState::Active);
+    }
+}
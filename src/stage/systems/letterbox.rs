@@ -0,0 +1,26 @@
+use crate::{
+    letterbox::events::LetterboxMoveTrigger,
+    stage::components::{MovementStageStep, Stage, StopStageStep},
+};
+use bevy::prelude::*;
+
+pub fn check_step_letterbox(
+    mut commands: Commands,
+    query: Query<
+        (Option<&MovementStageStep>, Option<&StopStageStep>),
+        (
+            With<Stage>,
+            Or<(Added<MovementStageStep>, Added<StopStageStep>)>,
+        ),
+    >,
+) {
+    for (movement_o, stop_o) in query.iter() {
+        let letterbox_o = movement_o
+            .and_then(|x| x.letterbox.as_ref())
+            .or(stop_o.and_then(|x| x.letterbox.as_ref()));
+
+        if let Some(letterbox) = letterbox_o {
+            commands.trigger(LetterboxMoveTrigger::from(letterbox.clone()));
+        }
+    }
+}
@@ -1,41 +1,57 @@
 pub mod camera;
+pub mod cinematic;
 pub mod damage;
+pub mod letterbox;
 pub mod movement;
+pub mod music;
 pub mod setup;
 pub mod spawn;
 pub mod state;
+pub mod stats;
 
 use super::{
     attack::components::EnemyAttack,
     bundles::*,
     components::{
         interactive::{Dead, Object},
+        movement::CameraPathMovement,
         placement::spawn_floor_depths,
-        CinematicStageStep, CurrentStageStep, MovementStageStep, Stage, StageElapsedStarted,
-        StageEntity, StopStageStep,
+        CurrentStageStep, MovementStageStep, Stage, StageElapsedStarted, StageEntity,
+        StopStageStep,
     },
     data::*,
     destructible::components::Destructible,
     enemy::components::Enemy,
     events::{NextStepEvent, StageClearedTrigger, StageDeathEvent},
     player::components::Player,
-    resources::{StageActionTimer, StageProgress, StageStepSpawner, StageTime},
+    resources::{
+        StageActionTimer, StageProgress, StageRecords, StageStatistics, StageStepSpawner,
+        StageTime, STAGE_RECORDS_FILE,
+    },
     StageProgressState,
 };
 use crate::components::VolumeSettings;
 use crate::{
     components::{DespawnMark, Music},
+    core::save::write_save,
     game::{
-        data::DEATH_SCORE_PENALTY, events::GameOverTrigger, resources::Lives,
-        score::components::Score, GameProgressState,
+        data::DEATH_SCORE_PENALTY,
+        events::GameOverTrigger,
+        resources::{GameFlags, Lives},
+        score::components::Score,
+        GameProgressState,
     },
     globals::{mark_for_despawn_by_query, DEBUG_STAGESTEP},
     input::GBInput,
     plugins::movement::linear::components::{
-        extra::LinearMovement2DReachCheck, LinearMovementBundle, LinearPositionRemovalBundle,
-        TargetingPositionX, TargetingPositionY,
+        extra::LinearMovement2DReachCheck, LinearMovementAcceleratedBundle, LinearMovementBundle,
+        LinearPositionRemovalBundle, TargetingPositionX, TargetingPositionY,
     },
     systems::{camera::CameraPos, spawn::make_music_bundle},
+    transitions::{
+        data::TransitionPhase,
+        events::{TransitionSource, TransitionStartupTrigger},
+    },
 };
 use assert_assets_path::assert_assets_path;
 use bevy::{audio::PlaybackMode, prelude::*};
@@ -87,10 +103,9 @@ pub fn spawn_current_stage_bundle(
     state.set(GameProgressState::Running);
 }
 
-// TODO combine the two and use just_finished and StageTime
-// TODO should be using StageTime instead of Time
-pub fn tick_stage_step_timer(mut timer: ResMut<StageActionTimer>, time: Res<Time>) {
-    timer.timer.tick(time.delta());
+// TODO combine the two and use just_finished
+pub fn tick_stage_step_timer(mut timer: ResMut<StageActionTimer>, time: Res<StageTime>) {
+    timer.timer.tick(time.delta);
 }
 
 pub fn check_stage_step_timer(timer: Res<StageActionTimer>, mut commands: Commands) {
@@ -159,6 +174,12 @@ pub fn on_stage_cleared(
     player_query: Query<Entity, With<Player>>,
     asset_server: Res<AssetServer>,
     volume_settings: Res<VolumeSettings>,
+    mut score: ResMut<Score>,
+    mut stats: ResMut<StageStatistics>,
+    mut records: ResMut<StageRecords>,
+    mut flags: ResMut<GameFlags>,
+    stage_data: Res<StageData>,
+    stage_time: Res<StageTime>,
 ) {
     mark_for_despawn_by_query(&mut commands, &destructible_query);
     mark_for_despawn_by_query(&mut commands, &enemy_query);
@@ -175,6 +196,23 @@ pub fn on_stage_cleared(
 
     commands.spawn((music_bundle, StageEntity));
 
+    for flag in stage_data.clear_flags.iter() {
+        flags.set(flag.clone());
+    }
+
+    if let Some(transition) = &stage_data.clear_transition {
+        commands.trigger(TransitionStartupTrigger {
+            data: transition.clone(),
+            source: TransitionSource::Stage,
+        });
+    }
+
+    stats.time_taken = stage_time.elapsed.saturating_sub(stats.started);
+    score.add_u(stats.grade().bonus_score());
+    if records.record(&stage_data.name, &stats) {
+        write_save(STAGE_RECORDS_FILE, records.as_ref());
+    }
+
     next_state.set(StageProgressState::Cleared);
 }
 
@@ -206,6 +244,7 @@ pub fn on_death(
     player_query: Query<Entity, With<Player>>,
     asset_server: Res<AssetServer>,
     volume_settings: Res<VolumeSettings>,
+    stage_data: Res<StageData>,
 ) {
     mark_for_despawn_by_query(&mut commands, &attack_query);
     mark_for_despawn_by_query(&mut commands, &destructible_query);
@@ -222,6 +261,21 @@ pub fn on_death(
     );
     commands.spawn((music_bundle, StageEntity));
 
+    match &stage_data.death_transition {
+        Some(transition) if transition.phase == TransitionPhase::In => {
+            warn!(
+                "Death transitions can't use the In phase, it would cover the screen, skipping it"
+            );
+        }
+        Some(transition) => {
+            commands.trigger(TransitionStartupTrigger {
+                data: transition.clone(),
+                source: TransitionSource::Stage,
+            });
+        }
+        None => {}
+    }
+
     if 0 == lives.0 {
         game_over_event_writer.send(GameOverTrigger { score: score.value });
         next_state.set(StageProgressState::GameOver);
@@ -265,54 +319,69 @@ pub fn read_step_trigger(
     }
 }
 
-pub fn initialise_cinematic_step(
-    mut next_state: ResMut<NextState<GameProgressState>>,
-    query: Query<(Entity, &CinematicStageStep), (With<Stage>, Added<CinematicStageStep>)>,
-) {
-    if let Ok((_, _)) = query.get_single() {
-        // next_state.set(GameState::Cutscene);
-    }
-}
-
 pub fn initialise_movement_step(
     mut commands: Commands,
     query: Query<(Entity, &MovementStageStep), (With<Stage>, Added<MovementStageStep>)>,
     camera_query: Query<(Entity, &PxSubPosition), With<CameraPos>>,
+    stage_time: Res<StageTime>,
 ) {
-    if let Ok((
-        _,
-        MovementStageStep {
-            coordinates,
-            base_speed,
-            spawns,
-            floor_depths,
-        },
-    )) = query.get_single()
-    {
+    if let Ok((_, step)) = query.get_single() {
         if let Ok((camera_entity, position)) = camera_query.get_single() {
-            let direction = *coordinates - position.0;
-            let speed = direction.normalize_or_zero() * base_speed.clone() * GAME_BASE_SPEED;
-
-            commands
-                .entity(camera_entity)
-                .insert(LinearMovementBundle::<StageTime, TargetingPositionX>::new(
-                    position.x,
-                    coordinates.x,
-                    speed.x,
-                ))
-                .insert(LinearMovementBundle::<StageTime, TargetingPositionY>::new(
-                    position.y,
-                    coordinates.y,
-                    speed.y,
-                ))
-                .insert(LinearMovement2DReachCheck::<
+            let mut entity_commands = commands.entity(camera_entity);
+
+            if step.is_linear() {
+                let direction = (step.coordinates - position.0).normalize_or_zero();
+                let speed = direction * step.base_speed * GAME_BASE_SPEED;
+
+                // The track drops decelerations that stop short, same as for curved steps
+                if let Some(acceleration) = step.track_from(position.0).acceleration {
+                    let acceleration = direction * acceleration;
+                    entity_commands
+                        .insert(LinearMovementAcceleratedBundle::<
+                            StageTime,
+                            TargetingPositionX,
+                        >::new(
+                            position.x, step.coordinates.x, speed.x, acceleration.x
+                        ))
+                        .insert(LinearMovementAcceleratedBundle::<
+                            StageTime,
+                            TargetingPositionY,
+                        >::new(
+                            position.y, step.coordinates.y, speed.y, acceleration.y
+                        ));
+                } else {
+                    entity_commands
+                        .insert(LinearMovementBundle::<StageTime, TargetingPositionX>::new(
+                            position.x,
+                            step.coordinates.x,
+                            speed.x,
+                        ))
+                        .insert(LinearMovementBundle::<StageTime, TargetingPositionY>::new(
+                            position.y,
+                            step.coordinates.y,
+                            speed.y,
+                        ));
+                }
+
+                entity_commands.insert(LinearMovement2DReachCheck::<
                     StageTime,
                     TargetingPositionX,
                     TargetingPositionY,
-                >::new())
-                .insert(StageStepSpawner::new(spawns.clone()));
+                >::new());
+            } else {
+                entity_commands.insert((
+                    TargetingPositionX(position.x),
+                    TargetingPositionY(position.y),
+                    CameraPathMovement {
+                        track: step.track_from(position.0),
+                        started: stage_time.elapsed,
+                    },
+                ));
+            }
+
+            entity_commands.insert(StageStepSpawner::new(step.spawns.clone()));
 
-            if let Some(floor_depths) = floor_depths {
+            if let Some(floor_depths) = &step.floor_depths {
                 spawn_floor_depths(&mut commands, floor_depths);
             }
         }
@@ -352,6 +421,8 @@ pub fn check_movement_step_reached(
         ),
         With<CameraPos>,
     >,
+    path_query: Query<(Entity, &CameraPathMovement), With<CameraPos>>,
+    stage_time: Res<StageTime>,
 ) {
     if let Ok((camera_entity, reach_check)) = camera_query.get_single() {
         if reach_check.reached() {
@@ -370,6 +441,19 @@ pub fn check_movement_step_reached(
             }
         }
     }
+
+    if let Ok((camera_entity, movement)) = path_query.get_single() {
+        if movement.started + movement.track.duration <= stage_time.elapsed {
+            for _ in step_query.iter() {
+                commands
+                    .entity(camera_entity)
+                    .remove::<CameraPathMovement>()
+                    .remove::<TargetingPositionX>()
+                    .remove::<TargetingPositionY>();
+                commands.trigger(NextStepEvent);
+            }
+        }
+    }
 }
 
 pub fn check_stop_step_finished_by_duration(
@@ -388,26 +472,6 @@ pub fn check_stop_step_finished_by_duration(
     }
 }
 
-pub fn update_cinematic_step(
-    mut commands: Commands,
-    query: Query<(Entity, &CinematicStageStep), With<Stage>>,
-) {
-    for (entity, _) in query.iter() {}
-}
-
-pub fn on_next_step_cleanup_cinematic_step(
-    trigger: Trigger<NextStepEvent>,
-    mut commands: Commands,
-    query: Query<(Entity, &CinematicStageStep), With<Stage>>,
-) {
-    for (entity, _) in query.iter() {
-        commands
-            .entity(entity)
-            .remove::<CinematicStageStep>()
-            .remove::<CurrentStageStep>();
-    }
-}
-
 pub fn on_next_step_cleanup_movement_step(
     trigger: Trigger<NextStepEvent>,
     mut commands: Commands,
@@ -0,0 +1,74 @@
+use crate::{
+    components::{Music, VolumeFade, VolumeLevel, VolumeSettings},
+    stage::{
+        components::{MovementStageStep, Stage, StageEntity, StageMusicChange, StopStageStep},
+        resources::StageTime,
+    },
+    systems::spawn::{make_faded_music_bundle, make_music_bundle},
+};
+use bevy::{audio::PlaybackMode, prelude::*};
+
+pub fn check_step_music(
+    mut commands: Commands,
+    query: Query<
+        (Option<&MovementStageStep>, Option<&StopStageStep>),
+        (
+            With<Stage>,
+            Or<(Added<MovementStageStep>, Added<StopStageStep>)>,
+        ),
+    >,
+    music_query: Query<(Entity, &VolumeLevel), (With<Music>, With<StageEntity>)>,
+    asset_server: Res<AssetServer>,
+    volume_settings: Res<VolumeSettings>,
+) {
+    for (movement_o, stop_o) in query.iter() {
+        let change_o = movement_o
+            .and_then(|x| x.music.as_ref())
+            .or(stop_o.and_then(|x| x.music.as_ref()));
+
+        match change_o {
+            Some(StageMusicChange::Track { path, fade }) => {
+                for (entity, level) in music_query.iter() {
+                    commands
+                        .entity(entity)
+                        .insert(VolumeFade::<StageTime>::fade_out(level.0, *fade));
+                }
+
+                let mut entity_commands = commands.spawn((StageEntity, Name::new("Stage music")));
+                if fade.is_zero() {
+                    entity_commands.insert(make_music_bundle(
+                        &asset_server,
+                        &volume_settings,
+                        path.clone(),
+                        PlaybackMode::Loop,
+                    ));
+                } else {
+                    entity_commands.insert((
+                        make_faded_music_bundle(
+                            &asset_server,
+                            &volume_settings,
+                            path.clone(),
+                            PlaybackMode::Loop,
+                        ),
+                        VolumeFade::<StageTime>::fade_in(*fade),
+                    ));
+                }
+            }
+            Some(StageMusicChange::FadeOut { fade }) => {
+                for (entity, level) in music_query.iter() {
+                    commands
+                        .entity(entity)
+                        .insert(VolumeFade::<StageTime>::fade_out(level.0, *fade));
+                }
+            }
+            Some(StageMusicChange::Duck { volume, fade }) => {
+                for (entity, level) in music_query.iter() {
+                    commands
+                        .entity(entity)
+                        .insert(VolumeFade::<StageTime>::new(level.0, *volume, *fade));
+                }
+            }
+            None => {}
+        }
+    }
+}
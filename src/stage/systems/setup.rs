@@ -1,16 +1,25 @@
 use super::spawn::*;
-use crate::components::VolumeSettings;
+use crate::components::{Music, VolumeSettings};
 use crate::{
+    globals::mark_for_despawn_by_query,
+    letterbox::events::LetterboxMoveTrigger,
     stage::{
-        bundles::{BackgroundBundle, SkyboxBundle},
-        components::{Stage, StageEntity},
+        attack::components::EnemyAttack,
+        bundles::{BackgroundBundle, ParallaxLayerBundle, SkyboxBundle},
+        catalogue::{ObjectCatalogue, ObjectCatalogueParam},
+        components::{interactive::Object, Stage, StageEntity},
         data::{StageData, StageSpawn},
-        events::StageStartupTrigger,
-        player::events::PlayerStartupTrigger,
+        destructible::components::Destructible,
+        enemy::components::Enemy,
+        events::{StageShutdownTrigger, StageStartupTrigger},
+        pickup::loot::LootRng,
+        player::{components::Player, events::PlayerStartupTrigger},
+        resources::{StageActionTimer, StageProgress, StageStatistics, StageTime},
         ui::hud::spawn::spawn_hud,
-        StagePluginUpdateState,
+        StagePluginUpdateState, StageProgressState,
     },
     systems::spawn::make_music_bundle,
+    transitions::events::TransitionShutdownTrigger,
 };
 use bevy::{audio::PlaybackMode, prelude::*};
 use seldom_pixel::{
@@ -27,11 +36,17 @@ pub fn on_stage_startup(
     mut next_state: ResMut<NextState<StagePluginUpdateState>>,
     asset_server: Res<AssetServer>,
     volume_settings: Res<VolumeSettings>,
+    stage_time: Res<StageTime>,
+    catalogue: ObjectCatalogueParam,
 ) {
     let data = trigger.event().data.as_ref();
+    let default_catalogue = ObjectCatalogue::default();
+    let catalogue = catalogue.get().unwrap_or(&default_catalogue);
     next_state.set(StagePluginUpdateState::Active);
 
     commands.insert_resource::<StageData>(data.clone());
+    commands.insert_resource(StageStatistics::new(stage_time.elapsed));
+    commands.insert_resource(LootRng::new(data.loot_seed));
 
     for spawn in &data.spawns {
         spawn_hud(
@@ -46,13 +61,13 @@ pub fn on_stage_startup(
 
         match spawn {
             StageSpawn::Destructible(spawn) => {
-                spawn_destructible(&mut commands, &mut assets_sprite, spawn);
+                spawn_destructible(&mut commands, &mut assets_sprite, catalogue, spawn);
             }
             StageSpawn::Enemy(spawn) => {
                 spawn_enemy(&mut commands, Vec2::ZERO, spawn);
             }
             StageSpawn::Object(spawn) => {
-                spawn_object(&mut commands, &mut assets_sprite, spawn);
+                spawn_object(&mut commands, &mut assets_sprite, catalogue, spawn);
             }
             StageSpawn::Pickup(spawn) => {
                 spawn_pickup(&mut commands, &mut assets_sprite, Vec2::ZERO, spawn);
@@ -67,6 +82,16 @@ pub fn on_stage_startup(
                 assets_sprite.load(data.background_path.clone()),
             ));
             p0.spawn(SkyboxBundle::new(&mut assets_sprite, data.skybox.clone()));
+
+            let camera_position = data.start_coordinates.unwrap_or(Vec2::ZERO);
+            for layer in &data.layers {
+                let (bundle, animation_o) =
+                    ParallaxLayerBundle::new(&mut assets_sprite, layer, camera_position);
+                let mut entity_commands = p0.spawn(bundle);
+                if let Some(animation) = animation_o {
+                    entity_commands.insert(animation);
+                }
+            }
         });
 
     // DEBUG
@@ -83,3 +108,38 @@ pub fn on_stage_startup(
 
     commands.trigger(PlayerStartupTrigger);
 }
+
+pub fn on_stage_shutdown(
+    _trigger: Trigger<StageShutdownTrigger>,
+    mut commands: Commands,
+    mut next_update_state: ResMut<NextState<StagePluginUpdateState>>,
+    mut next_progress_state: ResMut<NextState<StageProgressState>>,
+    mut progress: ResMut<StageProgress>,
+    mut timer: ResMut<StageActionTimer>,
+    stage_query: Query<Entity, With<Stage>>,
+    stage_entity_query: Query<Entity, With<StageEntity>>,
+    attack_query: Query<Entity, With<EnemyAttack>>,
+    destructible_query: Query<Entity, With<Destructible>>,
+    enemy_query: Query<Entity, With<Enemy>>,
+    music_query: Query<Entity, With<Music>>,
+    object_query: Query<Entity, With<Object>>,
+    player_query: Query<Entity, With<Player>>,
+) {
+    mark_for_despawn_by_query(&mut commands, &stage_query);
+    mark_for_despawn_by_query(&mut commands, &stage_entity_query);
+    mark_for_despawn_by_query(&mut commands, &attack_query);
+    mark_for_despawn_by_query(&mut commands, &destructible_query);
+    mark_for_despawn_by_query(&mut commands, &enemy_query);
+    mark_for_despawn_by_query(&mut commands, &music_query);
+    mark_for_despawn_by_query(&mut commands, &object_query);
+    mark_for_despawn_by_query(&mut commands, &player_query);
+
+    commands.trigger(LetterboxMoveTrigger::hide());
+    commands.trigger(TransitionShutdownTrigger);
+    commands.remove_resource::<StageData>();
+
+    progress.index = 0;
+    *timer = StageActionTimer::default();
+    next_progress_state.set(StageProgressState::Initial);
+    next_update_state.set(StagePluginUpdateState::Inactive);
+}
@@ -5,9 +5,8 @@ use crate::stage::{
         SpawnDrop, StageEntity,
     },
     data::ContainerSpawn,
-    destructible::{
-        components::{make_animation_bundle, DestructibleState},
-        data::destructibles::DESTRUCTIBLE_ANIMATIONS,
+    destructible::components::{
+        make_animation_bundle, DestructibleId, DestructibleMaxHealth, DestructibleState,
     },
     enemy::{
         entity::EnemyType,
@@ -19,25 +18,35 @@ use crate::stage::{
 };
 use crate::{
     layer::Layer,
+    resources::DifficultySelected,
     stage::{
+        catalogue::{ObjectCatalogue, ObjectCatalogueParam},
         components::{
             interactive::{Flickerer, Health, Hittable, Object},
             placement::Speed,
         },
-        data::{EnemySpawn, ObjectSpawn, ObjectType, PickupSpawn, PickupType, StageSpawn},
+        data::{EnemySpawn, ObjectSpawn, PickupSpawn, PickupType, StageSpawn},
         destructible::{components::Destructible, data::DestructibleSpawn},
         enemy::components::{behavior::EnemyBehaviors, Enemy},
         events::StageSpawnTrigger,
-        pickup::components::HealthRecovery,
+        pickup::{
+            components::{
+                BombSupply, HealthRecovery, PickupCollection, PickupFall, PickupLifetime,
+                PICKUP_DROP_MAX_DRIFT,
+            },
+            loot::{difficulty_loot_multiplier, LootRng, LootTable},
+        },
     },
     systems::camera::CameraPos,
 };
 use assert_assets_path::assert_assets_path;
 use bevy::prelude::*;
+use rand::Rng;
 use seldom_pixel::{
     prelude::{PxAnchor, PxAssets, PxSubPosition},
     sprite::{PxSprite, PxSpriteBundle},
 };
+use std::time::Duration;
 
 pub fn check_step_spawn(
     mut commands: Commands,
@@ -69,17 +78,20 @@ pub fn on_stage_spawn(
     mut commands: Commands,
     mut assets_sprite: PxAssets<PxSprite>,
     camera_query: Query<&PxSubPosition, With<CameraPos>>,
+    catalogue: ObjectCatalogueParam,
 ) {
+    let default_catalogue = ObjectCatalogue::default();
+    let catalogue = catalogue.get().unwrap_or(&default_catalogue);
     match &trigger.event().spawn {
         StageSpawn::Destructible(x) => {
-            spawn_destructible(&mut commands, &mut assets_sprite, x);
+            spawn_destructible(&mut commands, &mut assets_sprite, catalogue, x);
         }
         StageSpawn::Enemy(x) => {
             let camera_pos = camera_query.get_single().unwrap();
             spawn_enemy(&mut commands, camera_pos.0, x);
         }
         StageSpawn::Object(x) => {
-            spawn_object(&mut commands, &mut assets_sprite, x);
+            spawn_object(&mut commands, &mut assets_sprite, catalogue, x);
         }
         StageSpawn::Pickup(x) => {
             let camera_pos = camera_query.get_single().unwrap();
@@ -101,7 +113,7 @@ pub fn spawn_pickup(
         ..
     } = spawn;
     let position = PxSubPosition::from(offset + *coordinates);
-    match pickup_type {
+    let entity = match pickup_type {
         PickupType::BigHealthpack => {
             let sprite = assets_sprite.load(assert_assets_path!("sprites/pickups/health_4.png"));
             commands
@@ -142,7 +154,32 @@ pub fn spawn_pickup(
                 ))
                 .id()
         }
-    }
+        PickupType::Bomb => {
+            let sprite = assets_sprite.load(assert_assets_path!("sprites/pickups/bomb_6.png"));
+            commands
+                .spawn((
+                    spawn.get_name(),
+                    Hittable,
+                    PxSpriteBundle::<Layer> {
+                        sprite,
+                        anchor: PxAnchor::BottomCenter,
+                        layer: spawn.depth.to_layer(),
+                        ..default()
+                    },
+                    position,
+                    spawn.depth.clone(),
+                    Health(1),
+                    ColliderData::from_one(Collider::new_box(Vec2::new(7., 6.))),
+                    BombSupply(1),
+                ))
+                .id()
+        }
+    };
+
+    commands
+        .entity(entity)
+        .insert(PickupCollection::from_pickup_type(pickup_type));
+    entity
 }
 
 pub fn spawn_enemy(commands: &mut Commands, offset: Vec2, spawn: &EnemySpawn) -> Entity {
@@ -152,13 +189,14 @@ pub fn spawn_enemy(commands: &mut Commands, offset: Vec2, spawn: &EnemySpawn) ->
         speed,
         steps,
         contains,
+        drops,
         depth,
         ..
     } = spawn;
     let name = spawn.enemy_type.get_name();
     let position = offset + *coordinates;
     let behaviors = EnemyBehaviors::new(steps.clone());
-    match enemy_type {
+    let entity = match enemy_type {
         EnemyType::Mosquito => {
             let collider: Collider =
                 Collider::new_circle(ENEMY_MOSQUITO_RADIUS).with_offset(Vec2::new(0., 2.));
@@ -203,7 +241,15 @@ pub fn spawn_enemy(commands: &mut Commands, offset: Vec2, spawn: &EnemySpawn) ->
                 })
                 .id()
         }
-    }
+    };
+
+    let loot_table = match drops {
+        Some(drops) => LootTable(drops.clone()),
+        None => LootTable::from_enemy_type(enemy_type),
+    };
+
+    commands.entity(entity).insert((*enemy_type, loot_table));
+    entity
 }
 
 /**
@@ -212,58 +258,77 @@ pub fn spawn_enemy(commands: &mut Commands, offset: Vec2, spawn: &EnemySpawn) ->
 pub fn spawn_destructible(
     commands: &mut Commands,
     assets_sprite: &mut PxAssets<PxSprite>,
+    catalogue: &ObjectCatalogue,
     spawn: &DestructibleSpawn,
-) -> Entity {
-    let animations_map = &DESTRUCTIBLE_ANIMATIONS.get_animation_data(&spawn.destructible_type);
-    let animation_bundle_o = make_animation_bundle(
+) -> Option<Entity> {
+    let Some(entry) = catalogue.get_destructible(&spawn.id) else {
+        warn!("Destructible {} is not in the catalogue", spawn.id);
+        return None;
+    };
+    let Some(variant) = entry.get_variant(&spawn.depth) else {
+        warn!(
+            "Destructible {} has no variant for depth {}",
+            spawn.id,
+            spawn.depth.to_i8()
+        );
+        return None;
+    };
+    let animation_bundle = make_animation_bundle(
         assets_sprite,
-        animations_map,
-        &DestructibleState::Base,
+        &variant.by_state(&DestructibleState::Base),
         &spawn.depth,
     );
-    let animation_bundle = animation_bundle_o.unwrap();
+    let health = spawn.health.unwrap_or(entry.health);
 
-    commands
+    let entity = commands
         .spawn((
             Destructible,
             Flickerer,
             Hittable,
             spawn.get_name(),
             spawn.depth.clone(),
-            Health(spawn.health),
-            spawn.destructible_type.clone(),
+            Health(health),
+            DestructibleMaxHealth(health),
+            DestructibleState::Base,
+            DestructibleId(spawn.id.clone()),
             animation_bundle,
             PxSubPosition::from(spawn.coordinates.clone()),
             StageEntity,
         ))
-        .id()
+        .id();
+
+    if let Some(explosion) = spawn.explosion {
+        commands.entity(entity).insert(explosion);
+    }
+    Some(entity)
 }
 
 pub fn spawn_object(
     commands: &mut Commands,
     assets_sprite: &mut PxAssets<PxSprite>,
+    catalogue: &ObjectCatalogue,
     spawn: &ObjectSpawn,
-) -> Entity {
-    let sprite = assets_sprite.load(match spawn.object_type {
-        ObjectType::BenchBig => assert_assets_path!("sprites/objects/bench_big.png"),
-        ObjectType::BenchSmall => assert_assets_path!("sprites/objects/bench_small.png"),
-        ObjectType::Fibertree => assert_assets_path!("sprites/objects/fiber_tree.png"),
-        ObjectType::RugparkSign => assert_assets_path!("sprites/objects/rugpark_sign.png"),
-    });
-    commands
+) -> Option<Entity> {
+    let Some(entry) = catalogue.get_object(&spawn.id) else {
+        warn!("Object {} is not in the catalogue", spawn.id);
+        return None;
+    };
+    let sprite = assets_sprite.load(entry.sprite_path.clone());
+    let entity = commands
         .spawn((
             spawn.get_name(),
             Object,
             PxSpriteBundle::<Layer> {
                 sprite,
-                anchor: PxAnchor::BottomCenter,
+                anchor: entry.anchor.into(),
                 layer: spawn.depth.to_layer(),
                 ..default()
             },
             PxSubPosition::from(spawn.coordinates.clone()),
             StageEntity,
         ))
-        .id()
+        .id();
+    Some(entity)
 }
 
 pub fn check_dead_drop(
@@ -274,12 +339,18 @@ pub fn check_dead_drop(
 ) {
     for (spawn_drop, position, depth) in &mut query.iter() {
         let entity = match spawn_drop.contains.clone() {
-            ContainerSpawn::Pickup(spawn) => spawn_pickup(
-                &mut commands,
-                &mut assets_sprite,
-                Vec2::ZERO,
-                &spawn.from_spawn(position.0, depth.clone()),
-            ),
+            ContainerSpawn::Pickup(spawn) => {
+                let entity = spawn_pickup(
+                    &mut commands,
+                    &mut assets_sprite,
+                    Vec2::ZERO,
+                    &spawn.from_spawn(position.0, depth.clone()),
+                );
+                commands
+                    .entity(entity)
+                    .insert((PickupFall::dropped(0.), PickupLifetime::default()));
+                entity
+            }
             ContainerSpawn::Enemy(spawn) => spawn_enemy(
                 &mut commands,
                 Vec2::ZERO,
@@ -294,3 +365,37 @@ pub fn check_dead_drop(
         }
     }
 }
+
+pub fn check_dead_loot(
+    mut commands: Commands,
+    mut assets_sprite: PxAssets<PxSprite>,
+    mut rng: ResMut<LootRng>,
+    difficulty: Res<DifficultySelected>,
+    mut attack_query: Query<&mut UnhittableList, With<PlayerAttack>>,
+    query: Query<(Entity, &LootTable, &PxSubPosition, &Depth), Added<Dead>>,
+) {
+    let multiplier = difficulty_loot_multiplier(difficulty.0);
+    for (dead_entity, loot_table, position, depth) in query.iter() {
+        if let Some(pickup_type) = loot_table.roll(&mut rng.0, multiplier) {
+            let spawn = PickupSpawn {
+                pickup_type,
+                coordinates: position.0,
+                elapsed: Duration::ZERO,
+                depth: depth.clone(),
+            };
+            let entity = spawn_pickup(&mut commands, &mut assets_sprite, Vec2::ZERO, &spawn);
+            let drift = rng
+                .0
+                .gen_range(-PICKUP_DROP_MAX_DRIFT..=PICKUP_DROP_MAX_DRIFT);
+            commands
+                .entity(entity)
+                .insert((PickupFall::dropped(drift), PickupLifetime::default()));
+
+            for mut unhittable_list in &mut attack_query.iter_mut() {
+                if unhittable_list.0.contains(&dead_entity) {
+                    unhittable_list.0.insert(entity);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{
+    core::save::load_save,
+    stage::{
+        components::interactive::Dead,
+        enemy::{components::Enemy, entity::EnemyType},
+        events::DamageEvent,
+        pickup::components::{BombSupply, HealthRecovery},
+        player::components::{Player, PlayerAttack},
+        resources::{StageRecords, StageStatistics, STAGE_RECORDS_FILE},
+    },
+};
+use bevy::prelude::*;
+
+pub fn load_stage_records(mut commands: Commands) {
+    commands.insert_resource(load_save::<StageRecords>(STAGE_RECORDS_FILE).unwrap_or_default());
+}
+
+pub fn count_attacks_fired(
+    mut stats: ResMut<StageStatistics>,
+    query: Query<(), Added<PlayerAttack>>,
+) {
+    for _ in query.iter() {
+        stats.attacks_fired += 1;
+    }
+}
+
+pub fn count_kills(
+    mut stats: ResMut<StageStatistics>,
+    query: Query<&EnemyType, (With<Enemy>, Added<Dead>)>,
+) {
+    for enemy_type in query.iter() {
+        stats.add_kill(*enemy_type);
+    }
+}
+
+pub fn count_damage_taken(
+    mut stats: ResMut<StageStatistics>,
+    mut event_reader: EventReader<DamageEvent>,
+    player_query: Query<(), With<Player>>,
+) {
+    for e in event_reader.read() {
+        if player_query.contains(e.entity) {
+            stats.damage_taken += e.value;
+        }
+    }
+}
+
+pub fn count_pickups_collected(
+    mut stats: ResMut<StageStatistics>,
+    query: Query<(), (Or<(With<HealthRecovery>, With<BombSupply>)>, Added<Dead>)>,
+) {
+    for _ in query.iter() {
+        stats.pickups_collected += 1;
+    }
+}
@@ -7,6 +7,10 @@ pub struct ClearedScreen {}
 ///current score
 pub struct InfoText;
 
+#[derive(Component)]
+///stage statistics values
+pub struct StatText;
+
 #[derive(Component)]
 ///pause menu: text
 pub struct LivesText;
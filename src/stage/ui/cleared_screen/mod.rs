@@ -17,31 +17,58 @@ use crate::{
         mark_for_despawn_by_query, FONT_SIZE, SCREEN_RESOLUTION, TYPEFACE_CHARACTERS,
         TYPEFACE_INVERTED_PATH,
     },
+    input::GBInputBindings,
     layer::Layer,
-    stage::StageProgressState,
+    stage::{resources::StageStatistics, StageProgressState},
 };
 use bevy::prelude::*;
 use leafwing_input_manager::plugin::InputManagerPlugin;
 use seldom_pixel::prelude::{
     PxAnchor, PxAssets, PxCanvas, PxFilter, PxFilterLayers, PxLineBundle, PxTextBundle, PxTypeface,
 };
+use std::time::Duration;
 
 pub fn render_cleared_screen(
     mut commands: Commands,
     mut assets_typeface: PxAssets<PxTypeface>,
     mut assets_filter: PxAssets<PxFilter>,
     score: Res<Score>,
+    stats: Res<StageStatistics>,
     stage_state: Res<State<StageProgressState>>,
 ) {
     if stage_state.is_changed() && *stage_state.get() == StageProgressState::Cleared {
         let typeface =
             assets_typeface.load(TYPEFACE_INVERTED_PATH, TYPEFACE_CHARACTERS, [(' ', 4)]);
-        let score_text = score.value.to_string();
+        let grade = stats.grade();
+
+        let mut kills: Vec<_> = stats.kills.iter().collect();
+        kills.sort_by(|a, b| b.1.cmp(a.1));
+
+        let mut rows: Vec<(String, String)> =
+            vec![("Kills".to_string(), stats.total_kills().to_string())];
+        rows.extend(
+            kills
+                .into_iter()
+                .take(MAX_KILL_TYPE_ROWS)
+                .map(|(enemy_type, count)| (enemy_type.show_type(), count.to_string())),
+        );
+        rows.extend(vec![
+            (
+                "Accuracy".to_string(),
+                format!("{}%", (stats.accuracy() * 100.).round() as u32),
+            ),
+            ("Criticals".to_string(), stats.criticals.to_string()),
+            ("Damage".to_string(), stats.damage_taken.to_string()),
+            ("Time".to_string(), show_time_taken(stats.time_taken)),
+            ("Pickups".to_string(), stats.pickups_collected.to_string()),
+            ("Grade".to_string(), grade.show()),
+            ("Bonus".to_string(), grade.bonus_score().to_string()),
+        ]);
 
         commands
             .spawn((ClearedScreen {}, Name::new("Screen Cleared")))
             .with_children(|p0| {
-                for i in 25..(115 as i32) {
+                for i in SCREEN_BOTTOM..SCREEN_TOP {
                     p0.spawn((
                         PxLineBundle::<Layer> {
                             canvas: PxCanvas::Camera,
@@ -57,71 +84,117 @@ pub fn render_cleared_screen(
                         UIBackground {},
                         Name::new("UIBackground"),
                     ));
+                }
 
-                    p0.spawn((
-                        PxTextBundle::<Layer> {
-                            alignment: PxAnchor::BottomCenter,
-                            canvas: PxCanvas::Camera,
-                            layer: Layer::UI,
-                            rect: IRect::new(
-                                (SCREEN_RESOLUTION.x / 2) as i32 - HALF_SCREEN_SIZE,
-                                90,
-                                (SCREEN_RESOLUTION.x / 2) as i32 + HALF_SCREEN_SIZE,
-                                90 + (FONT_SIZE + 2) as i32,
-                            )
-                            .into(),
-                            text: "Stage  Cleared".into(),
-                            typeface: typeface.clone(),
-                            ..default()
-                        },
-                        InfoText,
-                        Name::new("InfoText_Stage_Cleared"),
-                    ));
+                let mut y = SCREEN_TOP - ROW_HEIGHT - 2;
 
-                    p0.spawn((
-                        PxTextBundle::<Layer> {
-                            alignment: PxAnchor::BottomCenter,
-                            canvas: PxCanvas::Camera,
-                            layer: Layer::UI,
-                            rect: IRect::new(
-                                (SCREEN_RESOLUTION.x / 2) as i32 - 40,
-                                60,
-                                (SCREEN_RESOLUTION.x / 2) as i32 + 40,
-                                60 + (FONT_SIZE + 2) as i32,
-                            )
-                            .into(),
-                            text: "Score:".into(),
-                            typeface: typeface.clone(),
-                            ..default()
-                        },
-                        InfoText,
-                        Name::new("InfoText_Score"),
-                    ));
+                p0.spawn((
+                    PxTextBundle::<Layer> {
+                        alignment: PxAnchor::BottomCenter,
+                        canvas: PxCanvas::Camera,
+                        layer: Layer::UI,
+                        rect: IRect::new(
+                            (SCREEN_RESOLUTION.x / 2) as i32 - HALF_SCREEN_SIZE,
+                            y,
+                            (SCREEN_RESOLUTION.x / 2) as i32 + HALF_SCREEN_SIZE,
+                            y + (FONT_SIZE + 2) as i32,
+                        )
+                        .into(),
+                        text: "Stage  Cleared".into(),
+                        typeface: typeface.clone(),
+                        ..default()
+                    },
+                    InfoText,
+                    Name::new("InfoText_Stage_Cleared"),
+                ));
 
-                    p0.spawn((
-                        PxTextBundle::<Layer> {
-                            alignment: PxAnchor::BottomCenter,
-                            canvas: PxCanvas::Camera,
-                            layer: Layer::UI,
-                            rect: IRect::new(
-                                (SCREEN_RESOLUTION.x / 2) as i32 - 40,
-                                50,
-                                (SCREEN_RESOLUTION.x / 2) as i32 + 40,
-                                50 + (FONT_SIZE + 2) as i32,
-                            )
-                            .into(),
-                            text: score_text.clone().into(),
-                            typeface: typeface.clone(),
-                            ..default()
-                        },
-                        ScoreText,
-                        Name::new("ScoreText"),
-                    ));
+                for (label, value) in rows {
+                    y -= ROW_HEIGHT;
+                    spawn_stat_row(p0, &typeface, y, label, value);
                 }
+
+                y -= ROW_HEIGHT;
+                p0.spawn((
+                    PxTextBundle::<Layer> {
+                        alignment: PxAnchor::BottomLeft,
+                        canvas: PxCanvas::Camera,
+                        layer: Layer::UI,
+                        rect: stat_row_rect(y).into(),
+                        text: "Score".into(),
+                        typeface: typeface.clone(),
+                        ..default()
+                    },
+                    InfoText,
+                    Name::new("InfoText_Score"),
+                ));
+
+                p0.spawn((
+                    PxTextBundle::<Layer> {
+                        alignment: PxAnchor::BottomRight,
+                        canvas: PxCanvas::Camera,
+                        layer: Layer::UI,
+                        rect: stat_row_rect(y).into(),
+                        text: score.value.to_string().into(),
+                        typeface: typeface.clone(),
+                        ..default()
+                    },
+                    ScoreText,
+                    Name::new("ScoreText"),
+                ));
             });
     }
 }
 
+fn stat_row_rect(y: i32) -> IRect {
+    IRect::new(
+        (SCREEN_RESOLUTION.x / 2) as i32 - HALF_SCREEN_SIZE + ROW_PADDING,
+        y,
+        (SCREEN_RESOLUTION.x / 2) as i32 + HALF_SCREEN_SIZE - ROW_PADDING,
+        y + (FONT_SIZE + 2) as i32,
+    )
+}
+
+fn spawn_stat_row(
+    p0: &mut ChildBuilder,
+    typeface: &Handle<PxTypeface>,
+    y: i32,
+    label: String,
+    value: String,
+) {
+    p0.spawn((
+        PxTextBundle::<Layer> {
+            alignment: PxAnchor::BottomLeft,
+            canvas: PxCanvas::Camera,
+            layer: Layer::UI,
+            rect: stat_row_rect(y).into(),
+            text: label.clone().into(),
+            typeface: typeface.clone(),
+            ..default()
+        },
+        InfoText,
+        Name::new(format!("InfoText_{}", label)),
+    ));
+
+    p0.spawn((
+        PxTextBundle::<Layer> {
+            alignment: PxAnchor::BottomRight,
+            canvas: PxCanvas::Camera,
+            layer: Layer::UI,
+            rect: stat_row_rect(y).into(),
+            text: value.into(),
+            typeface: typeface.clone(),
+            ..default()
+        },
+        StatText,
+        Name::new(format!("StatText_{}", label)),
+    ));
+}
+
+fn show_time_taken(time_taken: Duration) -> String {
+    let secs = time_taken.as_secs();
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
 pub fn despawn_cleared_screen(
     mut commands: Commands,
     stage_state: Res<State<StageProgressState>>,
@@ -133,11 +206,20 @@ pub fn despawn_cleared_screen(
 }
 
 pub const HALF_SCREEN_SIZE: i32 = 70;
+pub const SCREEN_BOTTOM: i32 = 16;
+pub const SCREEN_TOP: i32 = 140;
+pub const ROW_HEIGHT: i32 = 10;
+pub const ROW_PADDING: i32 = 8;
+/** Kill rows per enemy type, on top of the total, that still fit above SCREEN_BOTTOM. */
+pub const MAX_KILL_TYPE_ROWS: usize = 2;
 
 pub fn cleared_screen_plugin(app: &mut App) {
     app.add_event::<ClearScreenShutdownEvent>()
         .add_plugins(InputManagerPlugin::<ClearScreenInput>::default())
-        .add_systems(Startup, init_input)
+        .add_systems(
+            PreUpdate,
+            init_input.run_if(resource_changed::<GBInputBindings>),
+        )
         .add_systems(
             PostUpdate,
             check_press_continue_input.run_if(in_state(StageUiPluginUpdateState::Active)),
@@ -1,20 +1,20 @@
-use crate::input::GBInput;
+use crate::input::{GBInput, GBInputBindings};
 use bevy::prelude::*;
 use bevy::reflect::Reflect;
+use leafwing_input_manager::action_state::ActionState;
 use leafwing_input_manager::Actionlike;
-use leafwing_input_manager::{action_state::ActionState, input_map::InputMap};
 
 #[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
 pub enum DeathScreenInput {
     Restart,
 }
 
-pub fn init_input(mut commands: Commands) {
-    let ms: Vec<(DeathScreenInput, KeyCode)> = vec![
-        (DeathScreenInput::Restart, GBInput::B.into()),
-        (DeathScreenInput::Restart, GBInput::A.into()),
-        (DeathScreenInput::Restart, GBInput::Start.into()),
+pub fn init_input(mut commands: Commands, bindings: Res<GBInputBindings>) {
+    let ms: Vec<(DeathScreenInput, GBInput)> = vec![
+        (DeathScreenInput::Restart, GBInput::B),
+        (DeathScreenInput::Restart, GBInput::A),
+        (DeathScreenInput::Restart, GBInput::Start),
     ];
     commands.insert_resource(ActionState::<DeathScreenInput>::default());
-    commands.insert_resource(InputMap::<DeathScreenInput>::new(ms));
+    commands.insert_resource(bindings.make_input_map(ms));
 }
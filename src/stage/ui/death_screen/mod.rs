@@ -17,6 +17,7 @@ use crate::{
         mark_for_despawn_by_query, FONT_SIZE, SCREEN_RESOLUTION, TYPEFACE_CHARACTERS,
         TYPEFACE_INVERTED_PATH,
     },
+    input::GBInputBindings,
     layer::Layer,
     stage::StageProgressState,
 };
@@ -139,7 +140,10 @@ pub fn despawn_death_screen(
 pub fn death_screen_plugin(app: &mut App) {
     app.add_event::<DeathScreenRestartEvent>()
         .add_plugins(InputManagerPlugin::<DeathScreenInput>::default())
-        .add_systems(Startup, init_input)
+        .add_systems(
+            PreUpdate,
+            init_input.run_if(resource_changed::<GBInputBindings>),
+        )
         .add_systems(
             PostUpdate,
             check_press_continue_input.run_if(in_state(StageUiPluginUpdateState::Active)),
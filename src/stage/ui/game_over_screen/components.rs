@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use std::time::Duration;
 
 #[derive(Component)]
 pub struct GameOverScreen;
@@ -14,3 +15,29 @@ pub struct LivesText;
 
 #[derive(Component)]
 pub struct UIBackground;
+
+/**
+ * Seconds counted down on the continue prompt, from 9 to 0
+ */
+pub const CONTINUE_COUNTDOWN_SECS: u64 = 9;
+
+/**
+ * Runs on the app clock, as the stage clock stops on a game over
+ */
+#[derive(Component)]
+pub struct ContinueCountdown {
+    pub started: Duration,
+}
+
+impl ContinueCountdown {
+    /**
+     * None once the countdown has gone past 0
+     */
+    pub fn remaining(&self, elapsed: Duration) -> Option<u64> {
+        CONTINUE_COUNTDOWN_SECS.checked_sub(elapsed.saturating_sub(self.started).as_secs())
+    }
+
+    pub fn text(&self, elapsed: Duration) -> String {
+        format!("Continue? {}", self.remaining(elapsed).unwrap_or(0))
+    }
+}
@@ -1,20 +1,21 @@
-use crate::input::GBInput;
+use crate::input::{GBInput, GBInputBindings};
 use bevy::prelude::*;
 use bevy::reflect::Reflect;
+use leafwing_input_manager::action_state::ActionState;
 use leafwing_input_manager::Actionlike;
-use leafwing_input_manager::{action_state::ActionState, input_map::InputMap};
 
 #[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
 pub enum GameOverScreenInput {
     BackToMenu,
+    Continue,
 }
 
-pub fn init_input(mut commands: Commands) {
-    let ms: Vec<(GameOverScreenInput, KeyCode)> = vec![
-        (GameOverScreenInput::BackToMenu, GBInput::B.into()),
-        (GameOverScreenInput::BackToMenu, GBInput::A.into()),
-        (GameOverScreenInput::BackToMenu, GBInput::Start.into()),
+pub fn init_input(mut commands: Commands, bindings: Res<GBInputBindings>) {
+    let ms: Vec<(GameOverScreenInput, GBInput)> = vec![
+        (GameOverScreenInput::BackToMenu, GBInput::B),
+        (GameOverScreenInput::Continue, GBInput::A),
+        (GameOverScreenInput::Continue, GBInput::Start),
     ];
     commands.insert_resource(ActionState::<GameOverScreenInput>::default());
-    commands.insert_resource(InputMap::<GameOverScreenInput>::new(ms));
+    commands.insert_resource(bindings.make_input_map(ms));
 }
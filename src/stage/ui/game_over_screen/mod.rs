@@ -7,7 +7,7 @@ use self::{
     components::*,
     events::GameOverScreenShutdownEvent,
     input::{init_input, GameOverScreenInput},
-    systems::check_press_continue_input,
+    systems::{check_press_continue_input, update_continue_countdown},
 };
 use super::StageUiPluginUpdateState;
 use crate::{
@@ -17,6 +17,7 @@ use crate::{
         mark_for_despawn_by_query, FONT_SIZE, SCREEN_RESOLUTION, TYPEFACE_CHARACTERS,
         TYPEFACE_INVERTED_PATH,
     },
+    input::GBInputBindings,
     layer::Layer,
     stage::StageProgressState,
 };
@@ -34,11 +35,15 @@ pub fn render_game_over_screen(
     mut assets_filter: PxAssets<PxFilter>,
     score: Res<Score>,
     stage_state: Res<State<StageProgressState>>,
+    time: Res<Time>,
 ) {
     if stage_state.is_changed() && *stage_state.get() == StageProgressState::GameOver {
         let typeface =
             assets_typeface.load(TYPEFACE_INVERTED_PATH, TYPEFACE_CHARACTERS, [(' ', 4)]);
         let score_text = score.value.to_string();
+        let countdown = ContinueCountdown {
+            started: time.elapsed(),
+        };
 
         commands
             .spawn((GameOverScreen, Name::new("GameOver Screen")))
@@ -59,67 +64,87 @@ pub fn render_game_over_screen(
                         UIBackground {},
                         Name::new("UIBackground"),
                     ));
+                }
 
-                    p0.spawn((
-                        PxTextBundle::<Layer> {
-                            alignment: PxAnchor::BottomCenter,
-                            canvas: PxCanvas::Camera,
-                            layer: Layer::UI,
-                            rect: IRect::new(
-                                (SCREEN_RESOLUTION.x / 2) as i32 - HALF_SCREEN_SIZE,
-                                90,
-                                (SCREEN_RESOLUTION.x / 2) as i32 + HALF_SCREEN_SIZE,
-                                90 + (FONT_SIZE + 2) as i32,
-                            )
-                            .into(),
-                            text: "Game  Over".into(),
-                            typeface: typeface.clone(),
-                            ..default()
-                        },
-                        InfoText,
-                        Name::new("InfoText_Stage_GameOver"),
-                    ));
+                p0.spawn((
+                    PxTextBundle::<Layer> {
+                        alignment: PxAnchor::BottomCenter,
+                        canvas: PxCanvas::Camera,
+                        layer: Layer::UI,
+                        rect: IRect::new(
+                            (SCREEN_RESOLUTION.x / 2) as i32 - HALF_SCREEN_SIZE,
+                            90,
+                            (SCREEN_RESOLUTION.x / 2) as i32 + HALF_SCREEN_SIZE,
+                            90 + (FONT_SIZE + 2) as i32,
+                        )
+                        .into(),
+                        text: "Game  Over".into(),
+                        typeface: typeface.clone(),
+                        ..default()
+                    },
+                    InfoText,
+                    Name::new("InfoText_Stage_GameOver"),
+                ));
 
-                    p0.spawn((
-                        PxTextBundle::<Layer> {
-                            alignment: PxAnchor::BottomCenter,
-                            canvas: PxCanvas::Camera,
-                            layer: Layer::UI,
-                            rect: IRect::new(
-                                (SCREEN_RESOLUTION.x / 2) as i32 - 40,
-                                60,
-                                (SCREEN_RESOLUTION.x / 2) as i32 + 40,
-                                60 + (FONT_SIZE + 2) as i32,
-                            )
-                            .into(),
-                            text: "Score:".into(),
-                            typeface: typeface.clone(),
-                            ..default()
-                        },
-                        InfoText,
-                        Name::new("InfoText_Score"),
-                    ));
+                p0.spawn((
+                    PxTextBundle::<Layer> {
+                        alignment: PxAnchor::BottomCenter,
+                        canvas: PxCanvas::Camera,
+                        layer: Layer::UI,
+                        rect: IRect::new(
+                            (SCREEN_RESOLUTION.x / 2) as i32 - 40,
+                            60,
+                            (SCREEN_RESOLUTION.x / 2) as i32 + 40,
+                            60 + (FONT_SIZE + 2) as i32,
+                        )
+                        .into(),
+                        text: "Score:".into(),
+                        typeface: typeface.clone(),
+                        ..default()
+                    },
+                    InfoText,
+                    Name::new("InfoText_Score"),
+                ));
 
-                    p0.spawn((
-                        PxTextBundle::<Layer> {
-                            alignment: PxAnchor::BottomCenter,
-                            canvas: PxCanvas::Camera,
-                            layer: Layer::UI,
-                            rect: IRect::new(
-                                (SCREEN_RESOLUTION.x / 2) as i32 - 40,
-                                50,
-                                (SCREEN_RESOLUTION.x / 2) as i32 + 40,
-                                50 + (FONT_SIZE + 2) as i32,
-                            )
-                            .into(),
-                            text: score_text.clone().into(),
-                            typeface: typeface.clone(),
-                            ..default()
-                        },
-                        FinalScoreText,
-                        Name::new("FinalScoreText"),
-                    ));
-                }
+                p0.spawn((
+                    PxTextBundle::<Layer> {
+                        alignment: PxAnchor::BottomCenter,
+                        canvas: PxCanvas::Camera,
+                        layer: Layer::UI,
+                        rect: IRect::new(
+                            (SCREEN_RESOLUTION.x / 2) as i32 - 40,
+                            50,
+                            (SCREEN_RESOLUTION.x / 2) as i32 + 40,
+                            50 + (FONT_SIZE + 2) as i32,
+                        )
+                        .into(),
+                        text: score_text.clone().into(),
+                        typeface: typeface.clone(),
+                        ..default()
+                    },
+                    FinalScoreText,
+                    Name::new("FinalScoreText"),
+                ));
+
+                p0.spawn((
+                    PxTextBundle::<Layer> {
+                        alignment: PxAnchor::BottomCenter,
+                        canvas: PxCanvas::Camera,
+                        layer: Layer::UI,
+                        rect: IRect::new(
+                            (SCREEN_RESOLUTION.x / 2) as i32 - HALF_SCREEN_SIZE,
+                            32,
+                            (SCREEN_RESOLUTION.x / 2) as i32 + HALF_SCREEN_SIZE,
+                            32 + (FONT_SIZE + 2) as i32,
+                        )
+                        .into(),
+                        text: countdown.text(time.elapsed()).into(),
+                        typeface: typeface.clone(),
+                        ..default()
+                    },
+                    countdown,
+                    Name::new("ContinueText"),
+                ));
             });
     }
 }
@@ -137,9 +162,14 @@ pub fn despawn_game_over_screen(
 pub fn game_over_screen_plugin(app: &mut App) {
     app.add_event::<GameOverScreenShutdownEvent>()
         .add_plugins(InputManagerPlugin::<GameOverScreenInput>::default())
-        .add_systems(Startup, init_input)
+        .add_systems(
+            PreUpdate,
+            init_input.run_if(resource_changed::<GBInputBindings>),
+        )
         .add_systems(
             PostUpdate,
-            check_press_continue_input.run_if(in_state(StageUiPluginUpdateState::Active)),
+            (check_press_continue_input, update_continue_countdown)
+                .chain()
+                .run_if(in_state(StageUiPluginUpdateState::Active)),
         );
 }
@@ -1,12 +1,58 @@
-use super::{events::GameOverScreenShutdownEvent, input::GameOverScreenInput};
+use super::{
+    components::{ContinueCountdown, GameOverScreen},
+    events::GameOverScreenShutdownEvent,
+    input::GameOverScreenInput,
+};
+use crate::{
+    game::events::{GameContinueTrigger, GameReturnToTitleTrigger},
+    globals::mark_for_despawn_by_query,
+};
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::ActionState;
+use seldom_pixel::prelude::PxText;
 
 pub fn check_press_continue_input(
+    mut commands: Commands,
     mut screen_shutdown_event_writer: EventWriter<GameOverScreenShutdownEvent>,
     input: Res<ActionState<GameOverScreenInput>>,
+    screen_query: Query<Entity, With<GameOverScreen>>,
 ) {
-    if input.just_pressed(&GameOverScreenInput::BackToMenu) {
+    if screen_query.is_empty() {
+        return;
+    }
+
+    if input.just_pressed(&GameOverScreenInput::Continue) {
+        mark_for_despawn_by_query(&mut commands, &screen_query);
         screen_shutdown_event_writer.send(GameOverScreenShutdownEvent);
+        commands.trigger(GameContinueTrigger);
+    } else if input.just_pressed(&GameOverScreenInput::BackToMenu) {
+        mark_for_despawn_by_query(&mut commands, &screen_query);
+        screen_shutdown_event_writer.send(GameOverScreenShutdownEvent);
+        commands.trigger(GameReturnToTitleTrigger);
+    }
+}
+
+/**
+ * Goes back to the title once the countdown runs out
+ */
+pub fn update_continue_countdown(
+    mut commands: Commands,
+    mut screen_shutdown_event_writer: EventWriter<GameOverScreenShutdownEvent>,
+    mut query: Query<(Entity, &ContinueCountdown, &mut PxText)>,
+    screen_query: Query<Entity, With<GameOverScreen>>,
+    time: Res<Time>,
+) {
+    for (entity, countdown, mut text) in query.iter_mut() {
+        if countdown.remaining(time.elapsed()).is_some() {
+            let value = countdown.text(time.elapsed());
+            if text.0 != value {
+                text.0 = value;
+            }
+        } else {
+            commands.entity(entity).remove::<ContinueCountdown>();
+            mark_for_despawn_by_query(&mut commands, &screen_query);
+            screen_shutdown_event_writer.send(GameOverScreenShutdownEvent);
+            commands.trigger(GameReturnToTitleTrigger);
+        }
     }
 }
@@ -6,5 +6,8 @@ pub struct Hud;
 #[derive(Component)]
 pub struct HealthText;
 
+#[derive(Component)]
+pub struct BombText;
+
 #[derive(Component)]
 pub struct UIBackground;
@@ -10,7 +10,7 @@ impl Plugin for HudPlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<HudPluginUpdateState>().add_systems(
             Update,
-            (update_health_text).run_if(in_state(HudPluginUpdateState::Active)),
+            (update_health_text, update_bomb_text).run_if(in_state(HudPluginUpdateState::Active)),
         );
     }
 }
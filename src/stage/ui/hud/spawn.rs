@@ -1,4 +1,4 @@
-use super::components::{HealthText, Hud, UIBackground};
+use super::components::{BombText, HealthText, Hud, UIBackground};
 use crate::{
     globals::*,
     layer::Layer,
@@ -18,7 +18,11 @@ const LAYOUT_Y: i32 = 2;
 const HUD_HEALTH_W: i32 = 37;
 const HUD_HEALTH_ML: i32 = 15;
 
-const HUD_SCORE_W: i32 = 95;
+const HUD_BOMB_X: f32 = 56.;
+const HUD_BOMB_W: i32 = 14;
+const HUD_BOMB_ML: i32 = 62;
+
+const HUD_SCORE_W: i32 = 60;
 const HUD_SCORE_MR: i32 = 15;
 
 pub fn spawn_hud(
@@ -80,6 +84,40 @@ pub fn spawn_hud(
                 ));
             });
 
+            p0.spawn((Name::new("Bombs"),)).with_children(|parent| {
+                parent.spawn((
+                    PxSpriteBundle::<Layer> {
+                        anchor: PxAnchor::BottomLeft,
+                        canvas: PxCanvas::Camera,
+                        layer: Layer::Hud,
+                        sprite: assets_sprite
+                            .load(assert_assets_path!("sprites/pickups/bomb_6.png")),
+                        ..default()
+                    },
+                    PxSubPosition::from(Vec2::new(HUD_BOMB_X, LAYOUT_Y as f32)),
+                    Name::new("BombIcon"),
+                ));
+                parent.spawn((
+                    PxTextBundle::<Layer> {
+                        alignment: PxAnchor::BottomRight,
+                        canvas: PxCanvas::Camera,
+                        layer: Layer::Hud,
+                        rect: IRect::new(
+                            HUD_BOMB_ML,
+                            LAYOUT_Y,
+                            HUD_BOMB_ML + HUD_BOMB_W,
+                            LAYOUT_Y + (FONT_SIZE + 2) as i32,
+                        )
+                        .into(),
+                        text: "0".into(),
+                        typeface: typeface.clone(),
+                        ..default()
+                    },
+                    BombText,
+                    Name::new("BombText"),
+                ));
+            });
+
             p0.spawn((Name::new("Score"),)).with_children(|parent| {
                 parent.spawn((
                     PxTextBundle::<Layer> {
@@ -1,5 +1,7 @@
 use crate::stage::{
-    components::interactive::Health, player::components::Player, ui::hud::components::HealthText,
+    components::interactive::Health,
+    player::components::{BombStock, Player},
+    ui::hud::components::{BombText, HealthText},
 };
 use bevy::prelude::*;
 use seldom_pixel::prelude::PxText;
@@ -14,3 +16,14 @@ pub fn update_health_text(
         }
     }
 }
+
+pub fn update_bomb_text(
+    mut query: Query<&mut PxText, With<BombText>>,
+    player_query: Query<&BombStock, With<Player>>,
+) {
+    for stock in player_query.iter() {
+        for mut text in query.iter_mut() {
+            text.0 = stock.0.to_string();
+        }
+    }
+}
@@ -11,6 +11,7 @@ use self::{
     death_screen::death_screen_plugin,
     game_over_screen::game_over_screen_plugin,
     hud::HudPlugin,
+    pause_menu::pause_menu_plugin,
     systems::{
         state::{on_active, on_inactive},
         update_score_text,
@@ -27,6 +28,7 @@ impl Plugin for StageUiPlugin {
                 cleared_screen_plugin,
                 death_screen_plugin,
                 game_over_screen_plugin,
+                pause_menu_plugin,
             ))
             .init_state::<StageUiPluginUpdateState>()
             .add_systems(OnEnter(StageUiPluginUpdateState::Active), on_active)
@@ -35,7 +37,6 @@ impl Plugin for StageUiPlugin {
                 Update,
                 update_score_text.run_if(in_state(StageUiPluginUpdateState::Active)),
             );
-        // .add_plugins(PauseScreenPlugin);
     }
 }
 
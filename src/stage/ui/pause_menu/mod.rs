@@ -1,22 +1,36 @@
 pub mod pause_menu;
+pub mod resources;
+mod systems;
 
-use self::pause_menu::{InfoText, PauseMenu, ScoreText, UIBackground};
+use self::{
+    pause_menu::{InfoText, PauseMenu, PauseMenuLine, ScoreText, UIBackground, PAUSE_MENU_LINES},
+    resources::{PauseMenuEntry, PauseMenuSelection, PauseOptionsEntry},
+    systems::{
+        freeze_animations, on_pause, on_resume, pause_menu_select_change, pause_menu_select_option,
+    },
+};
 use crate::{
+    components::VolumeSettings,
     game::{score::components::Score, GameProgressState},
     globals::{
         mark_for_despawn_by_query, FONT_SIZE, SCREEN_RESOLUTION, TYPEFACE_CHARACTERS,
         TYPEFACE_INVERTED_PATH,
     },
     layer::Layer,
+    stage::player::crosshair::CrosshairSettings,
 };
 use bevy::prelude::*;
 use seldom_pixel::{
     prelude::{
-        PxAnchor, PxAssets, PxCanvas, PxFilter, PxFilterLayers, PxLineBundle, PxTextBundle,
+        PxAnchor, PxAssets, PxCanvas, PxFilter, PxFilterLayers, PxLineBundle, PxText, PxTextBundle,
         PxTypeface,
     },
     sprite::PxSprite,
 };
+use strum::IntoEnumIterator;
+
+const PAUSE_MENU_HALF_WIDTH: i32 = 50;
+const PAUSE_MENU_LINE_HEIGHT: i32 = (FONT_SIZE + 2) as i32;
 
 pub fn pause_menu_renderer(
     mut commands: Commands,
@@ -44,6 +58,15 @@ pub fn pause_menu_renderer(
     }
 }
 
+fn pause_menu_text_rect(bottom: i32) -> IRect {
+    IRect::new(
+        (SCREEN_RESOLUTION.x / 2) as i32 - PAUSE_MENU_HALF_WIDTH,
+        bottom,
+        (SCREEN_RESOLUTION.x / 2) as i32 + PAUSE_MENU_HALF_WIDTH,
+        bottom + PAUSE_MENU_LINE_HEIGHT,
+    )
+}
+
 pub fn spawn_pause_menu_bundle(
     commands: &mut Commands,
     typefaces: &mut PxAssets<PxTypeface>,
@@ -52,17 +75,17 @@ pub fn spawn_pause_menu_bundle(
     score: Res<Score>,
 ) -> Entity {
     let typeface = typefaces.load(TYPEFACE_INVERTED_PATH, TYPEFACE_CHARACTERS, [(' ', 4)]);
-    let score_text = score.value.to_string();
+    let score_text = format!("Score: {}", score.value);
     let entity = commands
         .spawn((PauseMenu {}, Name::new("PauseMenu")))
         .with_children(|p0| {
-            for i in 40..(100 as i32) {
+            for i in 28..(116 as i32) {
                 p0.spawn((
                     PxLineBundle::<Layer> {
                         canvas: PxCanvas::Camera,
                         line: [
-                            ((SCREEN_RESOLUTION.x / 2) as i32 - 40, i).into(),
-                            ((SCREEN_RESOLUTION.x / 2) as i32 + 40 as i32, i).into(),
+                            ((SCREEN_RESOLUTION.x / 2) as i32 - PAUSE_MENU_HALF_WIDTH, i).into(),
+                            ((SCREEN_RESOLUTION.x / 2) as i32 + PAUSE_MENU_HALF_WIDTH, i).into(),
                         ]
                         .into(),
                         layers: PxFilterLayers::single_over(Layer::UIBackground),
@@ -72,68 +95,130 @@ pub fn spawn_pause_menu_bundle(
                     UIBackground {},
                     Name::new("UIBackground"),
                 ));
+            }
 
-                p0.spawn((
-                    PxTextBundle::<Layer> {
-                        alignment: PxAnchor::BottomCenter,
-                        canvas: PxCanvas::Camera,
-                        layer: Layer::UI,
-                        rect: IRect::new(
-                            (SCREEN_RESOLUTION.x / 2) as i32 - 40,
-                            90,
-                            (SCREEN_RESOLUTION.x / 2) as i32 + 40,
-                            90 + (FONT_SIZE + 2) as i32,
-                        )
-                        .into(),
-                        text: "Paused".into(),
-                        typeface: typeface.clone(),
-                        ..default()
-                    },
-                    InfoText,
-                    Name::new("InfoText_Pause"),
-                ));
-
-                p0.spawn((
-                    PxTextBundle::<Layer> {
-                        alignment: PxAnchor::BottomCenter,
-                        canvas: PxCanvas::Camera,
-                        layer: Layer::UI,
-                        rect: IRect::new(
-                            (SCREEN_RESOLUTION.x / 2) as i32 - 40,
-                            60,
-                            (SCREEN_RESOLUTION.x / 2) as i32 + 40,
-                            60 + (FONT_SIZE + 2) as i32,
-                        )
-                        .into(),
-                        text: "Score:".into(),
-                        typeface: typeface.clone(),
-                        ..default()
-                    },
-                    InfoText,
-                    Name::new("InfoText_Score"),
-                ));
+            p0.spawn((
+                PxTextBundle::<Layer> {
+                    alignment: PxAnchor::BottomCenter,
+                    canvas: PxCanvas::Camera,
+                    layer: Layer::UI,
+                    rect: pause_menu_text_rect(100).into(),
+                    text: "Paused".into(),
+                    typeface: typeface.clone(),
+                    ..default()
+                },
+                InfoText,
+                Name::new("InfoText_Pause"),
+            ));
 
+            for row in 0..PAUSE_MENU_LINES {
                 p0.spawn((
                     PxTextBundle::<Layer> {
                         alignment: PxAnchor::BottomCenter,
                         canvas: PxCanvas::Camera,
                         layer: Layer::UI,
-                        rect: IRect::new(
-                            (SCREEN_RESOLUTION.x / 2) as i32 - 40,
-                            50,
-                            (SCREEN_RESOLUTION.x / 2) as i32 + 40,
-                            50 + (FONT_SIZE + 2) as i32,
-                        )
-                        .into(),
-                        text: score_text.clone().into(),
+                        rect: pause_menu_text_rect(84 - row as i32 * PAUSE_MENU_LINE_HEIGHT).into(),
+                        text: "".into(),
                         typeface: typeface.clone(),
                         ..default()
                     },
-                    ScoreText,
-                    Name::new("ScoreText"),
+                    PauseMenuLine(row),
+                    Name::new(format!("PauseMenuLine_{}", row)),
                 ));
             }
+
+            p0.spawn((
+                PxTextBundle::<Layer> {
+                    alignment: PxAnchor::BottomCenter,
+                    canvas: PxCanvas::Camera,
+                    layer: Layer::UI,
+                    rect: pause_menu_text_rect(32).into(),
+                    text: score_text.into(),
+                    typeface: typeface.clone(),
+                    ..default()
+                },
+                ScoreText,
+                Name::new("ScoreText"),
+            ));
         })
         .id();
     return entity;
 }
+
+fn pause_menu_line(label: String, selected: bool) -> String {
+    if selected {
+        format!("> {} <", label)
+    } else {
+        label
+    }
+}
+
+/**
+ * Lists the entries, or the options and their values while those are open
+ */
+pub fn update_pause_menu_lines(
+    mut title_query: Query<&mut PxText, (With<InfoText>, Without<PauseMenuLine>)>,
+    mut line_query: Query<(&PauseMenuLine, &mut PxText), Without<InfoText>>,
+    selection: Res<PauseMenuSelection>,
+    volume_settings: Res<VolumeSettings>,
+    crosshair_settings: Res<CrosshairSettings>,
+) {
+    let (title, lines): (&str, Vec<String>) = match selection.options_o {
+        Some(option) => (
+            "Options",
+            PauseOptionsEntry::iter()
+                .map(|entry| {
+                    let label = match entry {
+                        PauseOptionsEntry::Music => {
+                            format!("Music {}", VolumeSettings::level(volume_settings.music))
+                        }
+                        PauseOptionsEntry::Sfx => {
+                            format!("SFX {}", VolumeSettings::level(volume_settings.sfx))
+                        }
+                        PauseOptionsEntry::Crosshair => {
+                            format!("Crosshair {}", crosshair_settings.0 + 1)
+                        }
+                    };
+                    pause_menu_line(label, entry == option)
+                })
+                .collect(),
+        ),
+        None => (
+            "Paused",
+            PauseMenuEntry::iter()
+                .map(|entry| pause_menu_line(entry.label().to_string(), entry == selection.entry))
+                .collect(),
+        ),
+    };
+
+    for mut text in title_query.iter_mut() {
+        if text.0 != title {
+            text.0 = title.to_string();
+        }
+    }
+
+    for (line, mut text) in line_query.iter_mut() {
+        let value = lines.get(line.0).cloned().unwrap_or_default();
+        if text.0 != value {
+            text.0 = value;
+        }
+    }
+}
+
+pub fn pause_menu_plugin(app: &mut App) {
+    app.init_resource::<PauseMenuSelection>()
+        .add_systems(OnEnter(GameProgressState::Paused), on_pause)
+        .add_systems(OnExit(GameProgressState::Paused), on_resume)
+        .add_systems(
+            Update,
+            (update_pause_menu_lines, freeze_animations)
+                .run_if(in_state(GameProgressState::Paused)),
+        )
+        .add_systems(
+            PostUpdate,
+            (pause_menu_select_change, pause_menu_select_option)
+                // Chained so that the press that opens the options isn't read again by them
+                .chain()
+                .run_if(in_state(GameProgressState::Paused)),
+        );
+}
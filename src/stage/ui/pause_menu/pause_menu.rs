@@ -15,3 +15,13 @@ pub struct InfoText;
 #[derive(Component)]
 ///pause menu: BG
 pub struct UIBackground;
+
+#[derive(Component)]
+///pause menu: entry or option, by row
+pub struct PauseMenuLine(pub usize);
+
+#[derive(Component)]
+///audio paused along with the game, to be resumed with it
+pub struct PausedAudio;
+
+pub const PAUSE_MENU_LINES: usize = 4;
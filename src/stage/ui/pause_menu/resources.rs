@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+use strum_macros::EnumIter;
+
+#[derive(Clone, Copy, Debug, Default, EnumIter, Eq, PartialEq)]
+pub enum PauseMenuEntry {
+    #[default]
+    Resume,
+    Restart,
+    Options,
+    Quit,
+}
+
+impl PauseMenuEntry {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PauseMenuEntry::Resume => "Resume",
+            PauseMenuEntry::Restart => "Restart stage",
+            PauseMenuEntry::Options => "Options",
+            PauseMenuEntry::Quit => "Quit to title",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, EnumIter, Eq, PartialEq)]
+pub enum PauseOptionsEntry {
+    #[default]
+    Music,
+    Sfx,
+    Crosshair,
+}
+
+#[derive(Resource, Debug, Clone, Eq, PartialEq, Default)]
+pub struct PauseMenuSelection {
+    pub entry: PauseMenuEntry,
+    /**
+     * Set while the options are open
+     */
+    pub options_o: Option<PauseOptionsEntry>,
+}
+
+/**
+ * Clock multipliers from before the pause, slow-mo or debug tweaks included
+ */
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct PausedTimeMultipliers {
+    pub stage: f32,
+    pub letterbox: f32,
+}
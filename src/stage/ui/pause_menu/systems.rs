@@ -0,0 +1,175 @@
+use super::{
+    pause_menu::{PauseMenu, PausedAudio},
+    resources::{PauseMenuEntry, PauseMenuSelection, PauseOptionsEntry, PausedTimeMultipliers},
+};
+use crate::{
+    components::VolumeSettings,
+    core::time::TimeMultiplier,
+    game::{
+        events::{GameRestartStepTrigger, GameReturnToTitleTrigger},
+        GameProgressState,
+    },
+    globals::mark_for_despawn_by_query,
+    input::GBInput,
+    letterbox::resources::LetterboxTime,
+    stage::{player::crosshair::CrosshairSettings, resources::StageTime},
+};
+use bevy::{audio::AudioSinkPlayback, prelude::*};
+use leafwing_input_manager::prelude::ActionState;
+use seldom_pixel::prelude::PxAnimationStart;
+use strum::IntoEnumIterator;
+
+/**
+ * Freezes the stage and letterbox clocks and whatever audio is playing
+ */
+pub fn on_pause(
+    mut commands: Commands,
+    mut selection: ResMut<PauseMenuSelection>,
+    mut stage_multiplier: ResMut<TimeMultiplier<StageTime>>,
+    mut letterbox_multiplier: ResMut<TimeMultiplier<LetterboxTime>>,
+    query: Query<(Entity, &AudioSink)>,
+) {
+    *selection = PauseMenuSelection::default();
+    commands.insert_resource(PausedTimeMultipliers {
+        stage: stage_multiplier.value,
+        letterbox: letterbox_multiplier.value,
+    });
+    stage_multiplier.value = 0.;
+    letterbox_multiplier.value = 0.;
+
+    for (entity, sink) in query.iter() {
+        if !sink.is_paused() {
+            sink.pause();
+            commands.entity(entity).insert(PausedAudio);
+        }
+    }
+}
+
+/**
+ * Also takes the menu down, as the stage may be gone by the time its renderer would,
+ * and saves the options when Start resumes straight from them
+ */
+pub fn on_resume(
+    mut commands: Commands,
+    mut selection: ResMut<PauseMenuSelection>,
+    volume_settings: Res<VolumeSettings>,
+    crosshair_settings: Res<CrosshairSettings>,
+    mut stage_multiplier: ResMut<TimeMultiplier<StageTime>>,
+    mut letterbox_multiplier: ResMut<TimeMultiplier<LetterboxTime>>,
+    paused_multipliers_o: Option<Res<PausedTimeMultipliers>>,
+    query: Query<(Entity, &AudioSink), With<PausedAudio>>,
+    menu_query: Query<Entity, With<PauseMenu>>,
+) {
+    stage_multiplier.value = paused_multipliers_o.as_ref().map_or(1., |x| x.stage);
+    letterbox_multiplier.value = paused_multipliers_o.as_ref().map_or(1., |x| x.letterbox);
+    commands.remove_resource::<PausedTimeMultipliers>();
+    mark_for_despawn_by_query(&mut commands, &menu_query);
+
+    if selection.options_o.take().is_some() {
+        volume_settings.save();
+        crosshair_settings.save();
+    }
+
+    for (entity, sink) in query.iter() {
+        sink.play();
+        commands.entity(entity).remove::<PausedAudio>();
+    }
+}
+
+/**
+ * Animations run on the app clock, moving their start along keeps them on the same frame
+ */
+pub fn freeze_animations(mut query: Query<&mut PxAnimationStart>, time: Res<Time>) {
+    for mut start in query.iter_mut() {
+        start.0 += time.delta();
+    }
+}
+
+fn step_entry<T: IntoEnumIterator + PartialEq + Copy>(current: T, input: i8) -> T {
+    let entries = T::iter().collect::<Vec<_>>();
+    let index = entries.iter().position(|x| *x == current).unwrap_or(0) as i8;
+    usize::try_from(index + input)
+        .ok()
+        .and_then(|i| entries.get(i).copied())
+        .unwrap_or(current)
+}
+
+pub fn pause_menu_select_change(
+    mut selection: ResMut<PauseMenuSelection>,
+    gb_input: Res<ActionState<GBInput>>,
+) {
+    let input =
+        gb_input.just_pressed(&GBInput::Down) as i8 - gb_input.just_pressed(&GBInput::Up) as i8;
+    if input != 0 {
+        match selection.options_o {
+            Some(option) => selection.options_o = Some(step_entry(option, input)),
+            None => selection.entry = step_entry(selection.entry, input),
+        }
+    }
+}
+
+/**
+ * A picks the entry and B resumes, within the options Left and Right change the value
+ * and B saves them and goes back
+ */
+pub fn pause_menu_select_option(
+    mut commands: Commands,
+    mut selection: ResMut<PauseMenuSelection>,
+    mut next_state: ResMut<NextState<GameProgressState>>,
+    mut volume_settings: ResMut<VolumeSettings>,
+    mut crosshair_settings: ResMut<CrosshairSettings>,
+    gb_input: Res<ActionState<GBInput>>,
+) {
+    if let Some(option) = selection.options_o {
+        let input = gb_input.just_pressed(&GBInput::Right) as i8
+            - gb_input.just_pressed(&GBInput::Left) as i8;
+
+        match option {
+            PauseOptionsEntry::Music => {
+                if input != 0 {
+                    volume_settings.music = VolumeSettings::step(volume_settings.music, input);
+                }
+            }
+            PauseOptionsEntry::Sfx => {
+                if input != 0 {
+                    volume_settings.sfx = VolumeSettings::step(volume_settings.sfx, input);
+                }
+            }
+            PauseOptionsEntry::Crosshair => {
+                if input > 0 || gb_input.just_pressed(&GBInput::A) {
+                    *crosshair_settings = crosshair_settings.next();
+                } else if input < 0 {
+                    *crosshair_settings = crosshair_settings.previous();
+                }
+            }
+        }
+
+        if gb_input.just_pressed(&GBInput::B) {
+            volume_settings.save();
+            crosshair_settings.save();
+            selection.options_o = None;
+        }
+        return;
+    }
+
+    if gb_input.just_pressed(&GBInput::A) {
+        match selection.entry {
+            PauseMenuEntry::Resume => {
+                next_state.set(GameProgressState::Running);
+            }
+            PauseMenuEntry::Restart => {
+                next_state.set(GameProgressState::Running);
+                commands.trigger(GameRestartStepTrigger);
+            }
+            PauseMenuEntry::Options => {
+                selection.options_o = Some(PauseOptionsEntry::default());
+            }
+            PauseMenuEntry::Quit => {
+                next_state.set(GameProgressState::Running);
+                commands.trigger(GameReturnToTitleTrigger);
+            }
+        }
+    } else if gb_input.just_pressed(&GBInput::B) {
+        next_state.set(GameProgressState::Running);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{
+    components::{AudioSystemType, DespawnMark, VolumeFade, VolumeLevel, VolumeSettings},
+    core::time::DeltaTime,
+};
+use bevy::{audio::AudioSinkPlayback, prelude::*};
+
+pub fn update_volume_fade<T: DeltaTime + Resource>(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &mut VolumeFade<T>,
+        &mut VolumeLevel,
+        &AudioSystemType,
+        Option<&AudioSink>,
+    )>,
+    volume_settings: Res<VolumeSettings>,
+    time: Res<T>,
+) {
+    for (entity, mut fade, mut level, system_type, sink_o) in query.iter_mut() {
+        // The sink only shows up once playback has started, the fade waits on it
+        let Some(sink) = sink_o else {
+            continue;
+        };
+
+        fade.elapsed += time.delta();
+        level.0 = fade.level();
+
+        let channel = match system_type {
+            AudioSystemType::MUSIC => volume_settings.music,
+            AudioSystemType::SFX => volume_settings.sfx,
+        };
+        sink.set_volume(channel.get() * level.0);
+
+        if fade.is_finished() {
+            let mut entity_commands = commands.entity(entity);
+            entity_commands.remove::<VolumeFade<T>>();
+            if fade.despawn_on_finish {
+                entity_commands.insert(DespawnMark);
+            }
+        }
+    }
+}
+
+/**
+ * Sinks that are already playing don't pick the settings up on their own
+ */
+pub fn apply_volume_settings(
+    query: Query<(&AudioSink, &AudioSystemType, Option<&VolumeLevel>)>,
+    volume_settings: Res<VolumeSettings>,
+) {
+    for (sink, system_type, level_o) in query.iter() {
+        let channel = match system_type {
+            AudioSystemType::MUSIC => volume_settings.music,
+            AudioSystemType::SFX => volume_settings.sfx,
+        };
+        sink.set_volume(channel.get() * level_o.map_or(1., |x| x.0));
+    }
+}
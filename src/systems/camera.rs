@@ -1,32 +1,40 @@
-use bevy::{prelude::*, time::Time};
-use leafwing_input_manager::prelude::ActionState;
+use bevy::prelude::*;
 use seldom_pixel::prelude::{PxCamera, PxSubPosition};
-
-use crate::input::GBInput;
+#[cfg(debug_assertions)]
+use {crate::debug::input::DebugInput, leafwing_input_manager::prelude::ActionState};
 
 #[derive(Component)]
 pub struct CameraPos;
 
+#[cfg(debug_assertions)]
 const CAMERA_MOVEMENT_SPEED: f32 = 30.;
 
 // /**
 // DEBUG Move the camera placement using the debug arrow keys
 // */
+#[cfg(debug_assertions)]
 pub fn move_camera(
     mut camera_pos_query: Query<&mut PxSubPosition, With<CameraPos>>,
-    gb_input: Res<ActionState<GBInput>>,
+    debug_input: Res<ActionState<DebugInput>>,
     time: Res<Time>,
-    mut camera: ResMut<PxCamera>,
 ) {
     let mut camera_pos = camera_pos_query.single_mut();
     **camera_pos += IVec2::new(
-        gb_input.pressed(&GBInput::DRight) as i32 - gb_input.pressed(&GBInput::DLeft) as i32,
-        gb_input.pressed(&GBInput::DUp) as i32 - gb_input.pressed(&GBInput::DDown) as i32,
+        debug_input.pressed(&DebugInput::DRight) as i32
+            - debug_input.pressed(&DebugInput::DLeft) as i32,
+        debug_input.pressed(&DebugInput::DUp) as i32
+            - debug_input.pressed(&DebugInput::DDown) as i32,
     )
     .as_vec2()
     .normalize_or_zero()
         * time.delta().as_secs_f32()
         * CAMERA_MOVEMENT_SPEED;
+}
 
+pub fn update_camera(
+    camera_pos_query: Query<&PxSubPosition, With<CameraPos>>,
+    mut camera: ResMut<PxCamera>,
+) {
+    let camera_pos = camera_pos_query.single();
     **camera = camera_pos.round().as_ivec2();
 }
@@ -1,7 +1,9 @@
+pub mod audio;
 pub mod camera;
 pub mod movement;
 pub mod setup;
 pub mod spawn;
+pub mod tween;
 
 use crate::components::{AudioSystemType, VolumeSettings};
 use crate::game::events::GameStartupTrigger;
@@ -17,11 +19,11 @@ use seldom_pixel::prelude::PxAnimationFinished;
  * DEBUG
  */
 // pub fn input_exit_game(
-//     gb_input_query: Query<&ActionState<GBInput>>,
+//     debug_input_query: Query<&ActionState<DebugInput>>,
 //     mut exit: ResMut<Events<AppExit>>,
 // ) {
-//     let gb_input = gb_input_query.single();
-//     if gb_input.just_pressed(&GBInput::DExit) {
+//     let debug_input = debug_input_query.single();
+//     if debug_input.just_pressed(&DebugInput::DExit) {
 //         exit.send(AppExit);
 //     }
 // }
@@ -30,12 +32,12 @@ use seldom_pixel::prelude::PxAnimationFinished;
 //  * DEBUG
 //  */
 // pub fn transition_to_game_state(
-//     gb_input_query: Query<&ActionState<GBInput>>,
+//     debug_input_query: Query<&ActionState<DebugInput>>,
 //     app_state: Res<State<AppState>>,
 //     mut next_state: ResMut<NextState<AppState>>,
 // ) {
-//     let gb_input = gb_input_query.single();
-//     if gb_input.just_pressed(&GBInput::DToGame) {
+//     let debug_input = debug_input_query.single();
+//     if debug_input.just_pressed(&DebugInput::DToGame) {
 //         if app_state.get().to_owned() != AppState::Game {
 //             next_state.set(AppState::Game);
 //             info!("Entered AppState::Game");
@@ -47,12 +49,12 @@ use seldom_pixel::prelude::PxAnimationFinished;
 //  * DEBUG
 //  */
 // pub fn transition_to_main_menu_state(
-//     gb_input_query: Query<&ActionState<GBInput>>,
+//     debug_input_query: Query<&ActionState<DebugInput>>,
 //     app_state: Res<State<AppState>>,
 //     mut next_state: ResMut<NextState<AppState>>,
 // ) {
-//     let gb_input = gb_input_query.single();
-//     if gb_input.just_pressed(&GBInput::DToMainMenu) {
+//     let debug_input = debug_input_query.single();
+//     if debug_input.just_pressed(&DebugInput::DToMainMenu) {
 //         if app_state.get().to_owned() != AppState::MainMenu {
 //             // commands.insert_resource(NextState(Some(AppState::MainMenu)));
 //             next_state.set(AppState::MainMenu);
@@ -1,9 +1,13 @@
-use crate::input::GBInput;
+use crate::{
+    components::{VolumeSettings, VOLUME_SETTINGS_FILE},
+    core::save::load_save,
+    input::{GBInput, GBInputBindings, INPUT_BINDINGS_FILE},
+};
 
 use super::camera::CameraPos;
 use bevy::prelude::*;
 use bevy_framepace::Limiter;
-use leafwing_input_manager::prelude::{ActionState, InputMap};
+use leafwing_input_manager::prelude::ActionState;
 use seldom_pixel::prelude::PxSubPosition;
 
 pub fn set_framespace(mut settings: ResMut<bevy_framepace::FramepaceSettings>) {
@@ -15,25 +19,28 @@ pub fn spawn_camera(mut commands: Commands) {
     commands.spawn((PxSubPosition::default(), CameraPos));
 }
 
-pub fn init_gb_input(mut commands: Commands) {
-    let ms: Vec<(GBInput, KeyCode)> = vec![
-        (GBInput::Left, GBInput::Left.into()),
-        (GBInput::Up, GBInput::Up.into()),
-        (GBInput::Right, GBInput::Right.into()),
-        (GBInput::Down, GBInput::Down.into()),
-        (GBInput::B, GBInput::B.into()),
-        (GBInput::A, GBInput::A.into()),
-        (GBInput::Start, GBInput::Start.into()),
-        (GBInput::Select, GBInput::Select.into()),
-        // DEBUG
-        (GBInput::DToGame, GBInput::DToGame.into()),
-        (GBInput::DToMainMenu, GBInput::DToMainMenu.into()),
-        (GBInput::DExit, GBInput::DExit.into()),
-        (GBInput::DLeft, GBInput::DLeft.into()),
-        (GBInput::DUp, GBInput::DUp.into()),
-        (GBInput::DRight, GBInput::DRight.into()),
-        (GBInput::DDown, GBInput::DDown.into()),
+pub fn load_gb_input_bindings(mut commands: Commands) {
+    commands.insert_resource(load_save::<GBInputBindings>(INPUT_BINDINGS_FILE).unwrap_or_default());
+}
+
+pub fn load_volume_settings(mut commands: Commands) {
+    commands.insert_resource(load_save::<VolumeSettings>(VOLUME_SETTINGS_FILE).unwrap_or_default());
+}
+
+/**
+ * Rebuilt whenever the bindings change, same as every other screen's input map
+ */
+pub fn init_gb_input(mut commands: Commands, bindings: Res<GBInputBindings>) {
+    let ms: Vec<(GBInput, GBInput)> = vec![
+        (GBInput::Left, GBInput::Left),
+        (GBInput::Up, GBInput::Up),
+        (GBInput::Right, GBInput::Right),
+        (GBInput::Down, GBInput::Down),
+        (GBInput::B, GBInput::B),
+        (GBInput::A, GBInput::A),
+        (GBInput::Start, GBInput::Start),
+        (GBInput::Select, GBInput::Select),
     ];
     commands.insert_resource(ActionState::<GBInput>::default());
-    commands.insert_resource(InputMap::<GBInput>::new(ms));
+    commands.insert_resource(bindings.make_input_map(ms));
 }
@@ -1,19 +1,44 @@
-use crate::components::{AudioSystemBundle, AudioSystemType, Music, VolumeSettings};
-use bevy::{audio::PlaybackMode, prelude::*};
+use crate::components::{AudioSystemBundle, AudioSystemType, Music, VolumeLevel, VolumeSettings};
+use bevy::{
+    audio::{PlaybackMode, Volume},
+    prelude::*,
+};
 
 pub fn make_music_bundle(
     asset_server: &Res<AssetServer>,
     volume_settings: &Res<VolumeSettings>,
     music_path: String,
     mode: PlaybackMode,
-) -> (AudioBundle, AudioSystemBundle, Music) {
+) -> (AudioBundle, AudioSystemBundle, Music, VolumeLevel) {
+    make_music_bundle_at_level(asset_server, volume_settings, music_path, mode, 1.)
+}
+
+/**
+ * Starts silent, for music brought in by a VolumeFade
+ */
+pub fn make_faded_music_bundle(
+    asset_server: &Res<AssetServer>,
+    volume_settings: &Res<VolumeSettings>,
+    music_path: String,
+    mode: PlaybackMode,
+) -> (AudioBundle, AudioSystemBundle, Music, VolumeLevel) {
+    make_music_bundle_at_level(asset_server, volume_settings, music_path, mode, 0.)
+}
+
+fn make_music_bundle_at_level(
+    asset_server: &Res<AssetServer>,
+    volume_settings: &Res<VolumeSettings>,
+    music_path: String,
+    mode: PlaybackMode,
+    level: f32,
+) -> (AudioBundle, AudioSystemBundle, Music, VolumeLevel) {
     let source = asset_server.load(music_path);
     (
         AudioBundle {
             source,
             settings: PlaybackSettings {
                 mode,
-                volume: volume_settings.music.clone(),
+                volume: Volume::new(volume_settings.music.get() * level),
                 ..default()
             },
             ..default()
@@ -22,5 +47,6 @@ pub fn make_music_bundle(
             system_type: AudioSystemType::MUSIC,
         },
         Music,
+        VolumeLevel(level),
     )
 }
@@ -0,0 +1,30 @@
+use crate::{
+    core::time::DeltaTime,
+    plugins::movement::{
+        linear::components::{TargetingPositionX, TargetingPositionY},
+        track::TweenPath,
+    },
+};
+use bevy::prelude::*;
+
+pub fn update_tween_path<T: DeltaTime + Resource>(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &mut TweenPath<T>,
+        &mut TargetingPositionX,
+        &mut TargetingPositionY,
+    )>,
+    time: Res<T>,
+) {
+    for (entity, mut tween, mut x, mut y) in query.iter_mut() {
+        tween.elapsed += time.delta();
+        let position = tween.track.position_at(tween.elapsed);
+        x.0 = position.x;
+        y.0 = position.y;
+
+        if tween.is_finished() {
+            commands.entity(entity).remove::<TweenPath<T>>();
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use super::{data::TransitionData, events::TransitionSource};
+use crate::components::GBColor;
+use bevy::prelude::*;
+use std::time::Duration;
+
+#[derive(Clone, Component, Debug)]
+pub struct Transition {
+    pub data: TransitionData,
+    pub source: TransitionSource,
+    pub started: Duration,
+    pub color: GBColor,
+}
+
+/**
+ * One of the two covered stretches of a row or column
+ */
+#[derive(Clone, Copy, Component, Debug, PartialEq, Eq)]
+pub enum TransitionLine {
+    Start(u32),
+    End(u32),
+}
+
+impl TransitionLine {
+    pub fn index(&self) -> u32 {
+        match self {
+            TransitionLine::Start(index) | TransitionLine::End(index) => *index,
+        }
+    }
+}
@@ -1,12 +1,330 @@
+use crate::{components::GBColor, globals::SCREEN_RESOLUTION};
 use bevy::prelude::*;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DurationSecondsWithFrac};
+use std::time::Duration;
 
-#[derive(Clone, Debug)]
-pub enum TransitionVenetianDataState {
-    Opening,
-    Closing,
+/**
+ * Rows, or columns, covered together by each venetian blind
+ */
+pub const VENETIAN_BAND_SIZE: u32 = 8;
+/**
+ * Thickness of each ring drawn by the spiral
+ */
+pub const SPIRAL_RING_SIZE: u32 = 8;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Reflect, Serialize)]
+pub enum TransitionKind {
+    #[default]
+    Venetian,
+    /**
+     * Rings closing in from the edges of the screen
+     */
+    Spiral,
+    /**
+     * Steps through the palette towards the transition colour
+     */
+    Fade,
+    Wipe,
+}
+
+/**
+ * For the spiral, Right and Down turn clockwise, Left and Up counter-clockwise
+ */
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Reflect, Serialize)]
+pub enum TransitionDirection {
+    #[default]
+    Down,
+    Up,
+    Left,
+    Right,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Reflect, Serialize)]
+pub enum TransitionPhase {
+    /**
+     * Covers the screen and leaves it covered until the next transition
+     */
+    In,
+    /**
+     * Starts covered and reveals the screen
+     */
+    Out,
+    /**
+     * Covers the screen for the first half, reveals it on the second
+     */
+    #[default]
+    InOut,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransitionAxis {
+    Rows,
+    Columns,
+}
+
+impl TransitionAxis {
+    /**
+     * Number of lines drawn and the length of each
+     */
+    pub fn dimensions(&self) -> (u32, u32) {
+        match self {
+            TransitionAxis::Rows => (SCREEN_RESOLUTION.y, SCREEN_RESOLUTION.x),
+            TransitionAxis::Columns => (SCREEN_RESOLUTION.x, SCREEN_RESOLUTION.y),
+        }
+    }
+}
+
+/**
+ * Covered stretches of a single line, [0, start) and [end, length)
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransitionSpan {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl TransitionSpan {
+    pub fn empty(length: u32) -> Self {
+        Self {
+            start: 0,
+            end: length,
+        }
+    }
+
+    pub fn full(length: u32) -> Self {
+        Self {
+            start: length,
+            end: length,
+        }
+    }
+
+    pub fn mirror(self, length: u32) -> Self {
+        Self {
+            start: length - self.end,
+            end: length - self.start,
+        }
+    }
+}
+
+#[serde_as]
+#[derive(new, Clone, Debug, Deserialize, Reflect, Serialize)]
+pub struct TransitionData {
+    pub kind: TransitionKind,
+    #[serde_as(as = "DurationSecondsWithFrac")]
+    pub duration: Duration,
+    #[new(default)]
+    #[serde(default)]
+    pub direction: TransitionDirection,
+    #[new(default)]
+    #[serde(default)]
+    pub phase: TransitionPhase,
+    #[new(default)]
+    #[serde(default)]
+    pub color: GBColor,
+}
+
+impl TransitionData {
+    pub fn with_direction(mut self, direction: TransitionDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub fn with_phase(mut self, phase: TransitionPhase) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    pub fn with_color(mut self, color: GBColor) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn axis(&self) -> TransitionAxis {
+        match (self.kind, self.direction) {
+            (TransitionKind::Venetian, TransitionDirection::Left | TransitionDirection::Right) => {
+                TransitionAxis::Columns
+            }
+            _ => TransitionAxis::Rows,
+        }
+    }
+
+    /**
+     * How much of the screen is covered, from 0 to 1
+     */
+    pub fn coverage_at(&self, elapsed: Duration) -> f32 {
+        let t = if self.duration.is_zero() {
+            1.
+        } else {
+            (elapsed.as_secs_f32() / self.duration.as_secs_f32()).clamp(0., 1.)
+        };
+        match self.phase {
+            TransitionPhase::In => t,
+            TransitionPhase::Out => 1. - t,
+            TransitionPhase::InOut => 1. - (2. * t - 1.).abs(),
+        }
+    }
+
+    pub fn color_at(&self, coverage: f32) -> GBColor {
+        if self.kind != TransitionKind::Fade {
+            return self.color;
+        }
+        let palette = match self.color {
+            GBColor::Black | GBColor::DarkGray => {
+                [GBColor::LightGray, GBColor::DarkGray, GBColor::Black]
+            }
+            GBColor::LightGray | GBColor::White => {
+                [GBColor::DarkGray, GBColor::LightGray, GBColor::White]
+            }
+        };
+        let index = ((coverage * palette.len() as f32) as usize).min(palette.len() - 1);
+        palette[index]
+    }
+
+    /**
+     * Index counts from the bottom for rows, and from the left for columns
+     */
+    pub fn span_at(&self, index: u32, coverage: f32) -> TransitionSpan {
+        let (count, length) = self.axis().dimensions();
+        if coverage <= 0. {
+            return TransitionSpan::empty(length);
+        }
+        if coverage >= 1. {
+            return TransitionSpan::full(length);
+        }
+
+        match self.kind {
+            TransitionKind::Venetian => {
+                let covered = (coverage * VENETIAN_BAND_SIZE as f32) as u32;
+                let offset = index % VENETIAN_BAND_SIZE;
+                let offset = match self.direction {
+                    TransitionDirection::Down | TransitionDirection::Left => {
+                        VENETIAN_BAND_SIZE - 1 - offset
+                    }
+                    TransitionDirection::Up | TransitionDirection::Right => offset,
+                };
+                if offset < covered {
+                    TransitionSpan::full(length)
+                } else {
+                    TransitionSpan::empty(length)
+                }
+            }
+            TransitionKind::Fade => TransitionSpan::full(length),
+            TransitionKind::Wipe => {
+                let covered_lines = (coverage * count as f32) as u32;
+                let covered_length = (coverage * length as f32) as u32;
+                match self.direction {
+                    TransitionDirection::Down if index >= count - covered_lines => {
+                        TransitionSpan::full(length)
+                    }
+                    TransitionDirection::Up if index < covered_lines => {
+                        TransitionSpan::full(length)
+                    }
+                    TransitionDirection::Right => TransitionSpan {
+                        start: covered_length,
+                        end: length,
+                    },
+                    TransitionDirection::Left => TransitionSpan {
+                        start: 0,
+                        end: length - covered_length,
+                    },
+                    _ => TransitionSpan::empty(length),
+                }
+            }
+            TransitionKind::Spiral => {
+                let span = spiral_span(count - 1 - index, coverage, length, count);
+                match self.direction {
+                    TransitionDirection::Right | TransitionDirection::Down => span,
+                    TransitionDirection::Left | TransitionDirection::Up => span.mirror(length),
+                }
+            }
+        }
+    }
+}
+
+/**
+ * Edge lengths of a ring, in the order they're drawn: top, right, bottom and left
+ */
+fn spiral_ring_edges(ring: u32, width: u32, height: u32) -> Option<[u32; 4]> {
+    let inset = 2 * ring * SPIRAL_RING_SIZE;
+    if inset >= width || inset >= height {
+        return None;
+    }
+    let ring_width = width - inset;
+    let ring_height = height - inset;
+    Some([
+        ring_width,
+        ring_height.saturating_sub(SPIRAL_RING_SIZE),
+        ring_width.saturating_sub(SPIRAL_RING_SIZE),
+        ring_height.saturating_sub(2 * SPIRAL_RING_SIZE),
+    ])
 }
 
-#[derive(Clone, Debug, Resource)]
-pub struct TransitionVenetianData {
-    pub state: TransitionVenetianDataState,
+/**
+ * Clockwise spiral, with the row counted from the top of the screen
+ */
+fn spiral_span(row: u32, coverage: f32, width: u32, height: u32) -> TransitionSpan {
+    let t = SPIRAL_RING_SIZE;
+    let total: u32 = (0..)
+        .map_while(|ring| spiral_ring_edges(ring, width, height))
+        .map(|edges| edges.iter().sum::<u32>())
+        .sum();
+    let mut remaining = (coverage * total as f32) as u32;
+
+    let mut ring = 0;
+    while let Some(edges) = spiral_ring_edges(ring, width, height) {
+        let length: u32 = edges.iter().sum();
+        if remaining < length {
+            break;
+        }
+        remaining -= length;
+        ring += 1;
+    }
+
+    let inset = ring * t;
+    if row < inset || row >= height.saturating_sub(inset) {
+        return TransitionSpan::full(width);
+    }
+    let mut span = TransitionSpan {
+        start: inset,
+        end: width - inset,
+    };
+
+    let Some([top, right, bottom, left]) = spiral_ring_edges(ring, width, height) else {
+        return TransitionSpan::full(width);
+    };
+    let ring_bottom = height - inset;
+
+    let top_covered = remaining.min(top);
+    remaining -= top_covered;
+    if row < inset + t {
+        span.start = span.start.max(inset + top_covered);
+    }
+
+    let right_covered = remaining.min(right);
+    remaining -= right_covered;
+    if row >= inset + t && row < inset + t + right_covered {
+        span.end = span.end.min(width - inset - t);
+    }
+
+    let bottom_covered = remaining.min(bottom);
+    remaining -= bottom_covered;
+    if bottom_covered > 0 && row >= ring_bottom.saturating_sub(t) {
+        span.end = span
+            .end
+            .min((width - inset - t).saturating_sub(bottom_covered));
+    }
+
+    let left_covered = remaining.min(left);
+    let left_bottom = ring_bottom.saturating_sub(t);
+    if row < left_bottom && row >= left_bottom.saturating_sub(left_covered) {
+        span.start = span.start.max(inset + t);
+    }
+
+    if span.start >= span.end {
+        TransitionSpan::full(width)
+    } else {
+        span
+    }
 }
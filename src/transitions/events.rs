@@ -0,0 +1,32 @@
+use super::data::TransitionData;
+use bevy::prelude::*;
+
+/**
+ * Who started a transition, so that only they react to it finishing
+ */
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TransitionSource {
+    Cutscene,
+    GameStep,
+    Stage,
+}
+
+/**
+ * Replaces whichever transition is already on screen
+ */
+#[derive(Event)]
+pub struct TransitionStartupTrigger {
+    pub data: TransitionData,
+    pub source: TransitionSource,
+}
+
+/**
+ * Takes down whatever transition is on screen, finished or not
+ */
+#[derive(Event)]
+pub struct TransitionShutdownTrigger;
+
+#[derive(Clone, Event)]
+pub struct TransitionFinishedTrigger {
+    pub source: TransitionSource,
+}
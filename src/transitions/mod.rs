@@ -1,2 +1,49 @@
+pub mod components;
 pub mod data;
-pub mod spiral;
+pub mod events;
+pub mod resources;
+mod systems;
+
+use self::{
+    events::{TransitionFinishedTrigger, TransitionShutdownTrigger, TransitionStartupTrigger},
+    resources::TransitionTime,
+    systems::{
+        layout::{check_transition_finished, update_transition},
+        setup::{on_transition_shutdown, on_transition_startup},
+    },
+};
+use crate::core::{event::on_trigger_write_event, time::tick_time};
+use bevy::prelude::*;
+
+pub struct TransitionPlugin;
+
+impl Plugin for TransitionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<TransitionPluginUpdateState>()
+            .init_resource::<TransitionTime>()
+            .add_event::<TransitionStartupTrigger>()
+            .observe(on_transition_startup)
+            .add_event::<TransitionShutdownTrigger>()
+            .observe(on_transition_shutdown)
+            .add_event::<TransitionFinishedTrigger>()
+            .observe(on_trigger_write_event::<TransitionFinishedTrigger>)
+            .add_systems(
+                Update,
+                (
+                    tick_time::<TransitionTime>,
+                    (update_transition, check_transition_finished).chain(),
+                )
+                    .run_if(in_state(TransitionPluginUpdateState::Active)),
+            );
+    }
+}
+
+/**
+ * Active while a transition is moving, the game holds its progression until it finishes
+ */
+#[derive(States, Debug, Clone, Eq, PartialEq, Hash, Default)]
+pub enum TransitionPluginUpdateState {
+    #[default]
+    Inactive,
+    Active,
+}
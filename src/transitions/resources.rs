@@ -0,0 +1,31 @@
+use crate::core::time::{DeltaTime, ElapsedTime, Ticker};
+use bevy::prelude::*;
+use std::time::Duration;
+
+/**
+ * Keeps running while the stage and cutscene clocks are stopped
+ */
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct TransitionTime {
+    pub delta: Duration,
+    pub elapsed: Duration,
+}
+
+impl DeltaTime for TransitionTime {
+    fn delta(&self) -> Duration {
+        self.delta
+    }
+}
+
+impl ElapsedTime for TransitionTime {
+    fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+impl Ticker for TransitionTime {
+    fn tick(&mut self, delta: Duration) {
+        self.delta = delta;
+        self.elapsed += delta;
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    components::{Cleared, PxSpriteColorLoader},
+    transitions::{
+        components::{Transition, TransitionLine},
+        data::{TransitionAxis, TransitionPhase},
+        events::TransitionFinishedTrigger,
+        resources::TransitionTime,
+        TransitionPluginUpdateState,
+    },
+};
+use bevy::prelude::*;
+use seldom_pixel::prelude::{PxAssets, PxFilter, PxLine};
+
+pub fn update_transition(
+    mut filters: PxAssets<PxFilter>,
+    mut query: Query<(&mut Transition, &Children), Without<Cleared>>,
+    mut line_query: Query<(
+        &TransitionLine,
+        &mut PxLine,
+        &mut Visibility,
+        &mut Handle<PxFilter>,
+    )>,
+    time: Res<TransitionTime>,
+) {
+    for (mut transition, children) in query.iter_mut() {
+        let axis = transition.data.axis();
+        let (_, length) = axis.dimensions();
+        let coverage = transition
+            .data
+            .coverage_at(time.elapsed.saturating_sub(transition.started));
+
+        let color = transition.data.color_at(coverage);
+        let filter_o = (color != transition.color).then(|| filters.load_color(color));
+        transition.color = color;
+
+        let mut line_iter = line_query.iter_many_mut(children);
+        while let Some((line, mut px_line, mut visibility, mut filter)) = line_iter.fetch_next() {
+            let index = line.index() as i32;
+            let span = transition.data.span_at(line.index(), coverage);
+            let (from, to) = match line {
+                TransitionLine::Start(_) => (0, span.start as i32 - 1),
+                TransitionLine::End(_) => (span.end as i32, length as i32 - 1),
+            };
+
+            if from > to {
+                *visibility = Visibility::Hidden;
+                continue;
+            }
+            *visibility = Visibility::Inherited;
+            px_line.0 = match axis {
+                TransitionAxis::Rows => vec![IVec2::new(from, index), IVec2::new(to, index)],
+                TransitionAxis::Columns => vec![IVec2::new(index, from), IVec2::new(index, to)],
+            };
+            if let Some(new_filter) = &filter_o {
+                *filter = new_filter.clone();
+            }
+        }
+    }
+}
+
+/**
+ * A transition that leaves the screen covered stays around until the next one replaces it
+ */
+pub fn check_transition_finished(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<TransitionPluginUpdateState>>,
+    query: Query<(Entity, &Transition), Without<Cleared>>,
+    time: Res<TransitionTime>,
+) {
+    for (entity, transition) in query.iter() {
+        if time.elapsed.saturating_sub(transition.started) < transition.data.duration {
+            continue;
+        }
+
+        if transition.data.phase == TransitionPhase::In {
+            commands.entity(entity).insert(Cleared);
+        } else {
+            commands.entity(entity).despawn_recursive();
+        }
+        next_state.set(TransitionPluginUpdateState::Inactive);
+        commands.trigger(TransitionFinishedTrigger {
+            source: transition.source,
+        });
+    }
+}
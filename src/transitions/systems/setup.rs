@@ -0,0 +1,71 @@
+use crate::{
+    components::PxSpriteColorLoader,
+    layer::Layer,
+    transitions::{
+        components::{Transition, TransitionLine},
+        events::{TransitionShutdownTrigger, TransitionStartupTrigger},
+        resources::TransitionTime,
+        TransitionPluginUpdateState,
+    },
+};
+use bevy::prelude::*;
+use seldom_pixel::prelude::{PxAssets, PxCanvas, PxFilter, PxFilterLayers, PxLineBundle};
+
+pub fn on_transition_startup(
+    trigger: Trigger<TransitionStartupTrigger>,
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<TransitionPluginUpdateState>>,
+    mut filters: PxAssets<PxFilter>,
+    transition_query: Query<Entity, With<Transition>>,
+    time: Res<TransitionTime>,
+) {
+    for entity in transition_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    next_state.set(TransitionPluginUpdateState::Active);
+
+    let TransitionStartupTrigger { data, source } = trigger.event();
+    let data = data.clone();
+    let (count, _) = data.axis().dimensions();
+    let color = data.color_at(data.coverage_at(default()));
+    let filter = filters.load_color(color);
+
+    commands
+        .spawn((
+            Name::new("Transition"),
+            Transition {
+                data,
+                source: *source,
+                started: time.elapsed,
+                color,
+            },
+        ))
+        .with_children(|p0| {
+            for index in 0..count {
+                for line in [TransitionLine::Start(index), TransitionLine::End(index)] {
+                    p0.spawn((
+                        line,
+                        PxLineBundle::<Layer> {
+                            canvas: PxCanvas::Camera,
+                            layers: PxFilterLayers::single_over(Layer::Transition),
+                            filter: filter.clone(),
+                            visibility: Visibility::Hidden,
+                            ..default()
+                        },
+                    ));
+                }
+            }
+        });
+}
+
+pub fn on_transition_shutdown(
+    _trigger: Trigger<TransitionShutdownTrigger>,
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<TransitionPluginUpdateState>>,
+    transition_query: Query<Entity, With<Transition>>,
+) {
+    for entity in transition_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    next_state.set(TransitionPluginUpdateState::Inactive);
+}
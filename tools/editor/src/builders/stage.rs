@@ -10,6 +10,7 @@ use bevy_prototype_lyon::geometry::GeometryBuilder;
 use bevy_prototype_lyon::path::PathBuilder;
 use bevy_prototype_lyon::shapes::Polygon;
 use carcinisation::globals::SCREEN_RESOLUTION;
+use carcinisation::layer::Layer;
 use carcinisation::stage::data::{StageData, StageStep};
 
 use crate::components::{AnimationIndices, AnimationTimer, Draggable, SceneItem, StageSpawnLabel};
@@ -63,12 +64,16 @@ pub fn spawn_path(
     for (index, step) in stage_data.steps.iter().enumerate() {
         match step {
             StageStep::Cinematic(s) => {
-                // TODO
+                current_elapsed += s.to_scene().expected_duration();
             }
             StageStep::Movement(s) => {
-                path_builder.line_to(s.coordinates + h_screen_resolution);
+                let track = s.track_from(current_position);
+                for point in track.points.iter().skip(1) {
+                    path_builder.line_to(*point + h_screen_resolution);
+                }
 
-                let direction = (current_position - s.coordinates).normalize_or_zero();
+                let next_position = track.points.get(1).copied().unwrap_or(s.coordinates);
+                let direction = (current_position - next_position).normalize_or_zero();
                 let angle = direction.y.atan2(direction.x);
 
                 let arrow_shape = Polygon {
@@ -94,10 +99,8 @@ pub fn spawn_path(
                     Fill::color(Color::CYAN),
                 ));
 
-                let distance = s.coordinates.distance(current_position);
-                let time_to_move = distance / s.base_speed;
                 current_position = s.coordinates;
-                current_elapsed += Duration::from_secs_f32(time_to_move);
+                current_elapsed += track.duration;
             }
             StageStep::Stop(s) => {
                 current_elapsed += s.max_duration.unwrap_or(Duration::ZERO);
@@ -122,6 +125,20 @@ pub fn spawn_path(
     ));
 }
 
+/**
+ * Keeps the layers in between the skybox, background and spawns as they are in game
+ */
+fn get_layer_editor_z_index(layer: &Layer) -> f32 {
+    match layer {
+        Layer::Skybox => SKYBOX_Z + 0.5,
+        Layer::PreBackgroundDepth(_) => BACKGROUND_Z - 0.5,
+        Layer::Background => BACKGROUND_Z + 0.1,
+        // MidDepth counts down from Six
+        Layer::MidDepth(depth) => depth.clone() as i8 as f32 - 6.0 + 0.05,
+        _ => PATH_Z - 0.5,
+    }
+}
+
 pub fn spawn_stage(
     mut commands: &mut Commands,
     asset_server: &Res<AssetServer>,
@@ -147,6 +164,33 @@ pub fn spawn_stage(
         ));
     }
 
+    if stage_controls_ui.background_is_visible() {
+        let camera_position =
+            stage_data.calculate_camera_position(stage_controls_ui.ElapsedDuration);
+        let elapsed = stage_controls_ui.ElapsedDuration.as_secs_f32();
+
+        for (index, layer) in stage_data.layers.iter().enumerate() {
+            let scrolled = layer.autoscroll.unwrap_or(Vec2::ZERO) * elapsed;
+            let position = camera_position * (1. - layer.factor) + layer.offset + scrolled;
+            // TODO animated layers are shown whole, rather than by their first frame
+            commands.spawn((
+                Name::new(format!("SG Layer {}", index)),
+                SceneItem,
+                SpriteBundle {
+                    texture: asset_server.load(&layer.path),
+                    transform: Transform::from_translation(
+                        position.extend(get_layer_editor_z_index(&layer.layer)),
+                    ),
+                    sprite: Sprite {
+                        anchor: Anchor::BottomLeft,
+                        ..default()
+                    },
+                    ..default()
+                },
+            ));
+        }
+    }
+
     if stage_controls_ui.skybox_is_visible() {
         let texture = asset_server.load(&stage_data.skybox.path);
         let texture_atlas_layout =
@@ -1,10 +1,23 @@
+use crate::constants::ASSETS_PATH;
 use bevy::math::{Rect, URect};
 use carcinisation::stage::{
+    catalogue::{ObjectCatalogue, OBJECT_CATALOGUE_PATH},
     components::placement::Depth,
-    data::{ObjectType, PickupType},
-    destructible::components::DestructibleType,
+    data::PickupType,
     enemy::entity::EnemyType,
 };
+use lazy_static::lazy_static;
+
+lazy_static! {
+    /**
+     * Read once on startup, the editor needs to be restarted to pick up catalogue changes
+     */
+    static ref OBJECT_CATALOGUE: ObjectCatalogue =
+        std::fs::read_to_string(format!("{}{}", ASSETS_PATH, OBJECT_CATALOGUE_PATH))
+            .ok()
+            .and_then(|content| ron::de::from_str(&content).ok())
+            .unwrap_or_default();
+}
 
 pub fn get_enemy_thumbnail(enemy_type: EnemyType, depth: Depth) -> (String, Option<Rect>) {
     match enemy_type {
@@ -91,30 +104,31 @@ pub fn get_enemy_thumbnail(enemy_type: EnemyType, depth: Depth) -> (String, Opti
     }
 }
 
-pub fn get_destructible_thumbnail(
-    destructible_type: DestructibleType,
-    depth: Depth,
-) -> (String, Option<Rect>) {
-    match destructible_type {
-        DestructibleType::Crystal => ("sprites/objects/crystal_base_5.png".into(), None),
-        DestructibleType::Lamp => ("sprites/objects/lamp_base_3.png".into(), None),
-        DestructibleType::Mushroom => ("sprites/objects/mushroom_base_4.png".into(), None),
-        DestructibleType::Trashcan => ("sprites/objects/trashcan_base_6.png".into(), None),
-    }
+pub fn get_destructible_thumbnail(id: &str, depth: Depth) -> (String, Option<Rect>) {
+    let sprite_path = OBJECT_CATALOGUE
+        .get_destructible(id)
+        .and_then(|entry| {
+            entry
+                .get_variant(&depth)
+                .or_else(|| entry.get_variant(&entry.depth))
+        })
+        .map(|variant| variant.base.sprite_path.clone())
+        .unwrap_or_default();
+    (sprite_path, None)
 }
 
-pub fn get_object_thumbnail(object_type: ObjectType, depth: Depth) -> (String, Option<Rect>) {
-    match object_type {
-        ObjectType::BenchBig => ("sprites/objects/bench_big.png".into(), None),
-        ObjectType::BenchSmall => ("sprites/objects/bench_small.png".into(), None),
-        ObjectType::Fibertree => ("sprites/objects/fiber_tree.png".into(), None),
-        ObjectType::RugparkSign => ("sprites/objects/rugpark_sign.png".into(), None),
-    }
+pub fn get_object_thumbnail(id: &str, depth: Depth) -> (String, Option<Rect>) {
+    let sprite_path = OBJECT_CATALOGUE
+        .get_object(id)
+        .map(|entry| entry.sprite_path.clone())
+        .unwrap_or_default();
+    (sprite_path, None)
 }
 
 pub fn get_pickup_thumbnail(pickup_type: PickupType, depth: Depth) -> (String, Option<Rect>) {
     match pickup_type {
         PickupType::BigHealthpack => ("sprites/pickups/health_6.png".into(), None),
         PickupType::SmallHealthpack => ("sprites/pickups/health_6.png".into(), None),
+        PickupType::Bomb => ("sprites/pickups/bomb_6.png".into(), None),
     }
 }
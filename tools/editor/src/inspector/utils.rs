@@ -24,17 +24,14 @@ impl StageDataUtils for StageData {
         for step in &self.steps {
             match step {
                 StageStep::Movement(s) => {
-                    let distance = s.coordinates.distance(current_position);
-                    let time_to_move = Duration::from_secs_f32(distance / s.base_speed);
+                    let track = s.track_from(current_position);
 
-                    if current_elapsed + time_to_move > elapsed {
-                        let t =
-                            (elapsed - current_elapsed).as_secs_f32() / time_to_move.as_secs_f32();
-                        return current_position.lerp(s.coordinates, t);
+                    if current_elapsed + track.duration > elapsed {
+                        return track.position_at(elapsed - current_elapsed);
                     }
 
                     current_position = s.coordinates;
-                    current_elapsed += time_to_move;
+                    current_elapsed += track.duration;
                 }
                 StageStep::Stop(s) => {
                     current_elapsed += s.max_duration.unwrap_or(Duration::ZERO);
@@ -43,8 +40,12 @@ impl StageDataUtils for StageData {
                         return current_position;
                     }
                 }
-                StageStep::Cinematic(_) => {
-                    // Handle Cinematic step if necessary
+                StageStep::Cinematic(s) => {
+                    current_elapsed += s.to_scene().expected_duration();
+
+                    if current_elapsed > elapsed {
+                        return current_position;
+                    }
                 }
             }
         }
@@ -92,14 +93,14 @@ impl StageSpawnUtils for StageSpawn {
 
     fn get_thumbnail(&self) -> (String, Option<Rect>) {
         match self {
-            StageSpawn::Destructible(DestructibleSpawn {
-                destructible_type, ..
-            }) => get_destructible_thumbnail(destructible_type.clone(), self.get_depth()),
+            StageSpawn::Destructible(DestructibleSpawn { id, .. }) => {
+                get_destructible_thumbnail(id, self.get_depth())
+            }
             StageSpawn::Enemy(EnemySpawn { enemy_type, .. }) => {
                 get_enemy_thumbnail(enemy_type.clone(), self.get_depth())
             }
-            StageSpawn::Object(ObjectSpawn { object_type, .. }) => {
-                get_object_thumbnail(object_type.clone(), self.get_depth())
+            StageSpawn::Object(ObjectSpawn { id, .. }) => {
+                get_object_thumbnail(id, self.get_depth())
             }
             StageSpawn::Pickup(PickupSpawn { pickup_type, .. }) => {
                 get_pickup_thumbnail(pickup_type.clone(), self.get_depth())